@@ -0,0 +1,275 @@
+use std::borrow::Cow;
+use std::cmp::Reverse;
+
+/// Limits on the length of a type name. See [`abbreviate`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Abbreviation {
+    /// Brackets nested deeper than this have their contents replaced with `…`.
+    ///
+    /// The arguments of the outermost type are at depth 1, so a depth of 1 turns
+    /// `Map<Filter<Iter<u8>, F>, G>` into `Map<Filter<…>, G>`.
+    pub max_depth: Option<usize>,
+    /// The name is shortened until it is no more than this many `char`s long, if possible.
+    pub max_width: Option<usize>,
+}
+impl Abbreviation {
+    pub fn depth(max_depth: usize) -> Self {
+        Abbreviation { max_depth: Some(max_depth), max_width: None }
+    }
+    pub fn width(max_width: usize) -> Self {
+        Abbreviation { max_depth: None, max_width: Some(max_width) }
+    }
+    pub fn is_unlimited(&self) -> bool {
+        self.max_depth.is_none() && self.max_width.is_none()
+    }
+}
+
+/// Shortens a long type name by replacing the contents of brackets with `…`.
+///
+/// The [`max_depth`](Abbreviation::max_depth) is applied first. Then, until the name fits in
+/// [`max_width`](Abbreviation::max_width), brackets are collapsed from the innermost out, then
+/// the arguments of the outermost types, longest first, and finally the outermost brackets. The
+/// outermost type is never removed, so the name may still be too wide.
+/// ```
+/// # use ezty::{abbreviate, Abbreviation};
+/// let name = "Map<Filter<vec::IntoIter<u8>, main::{{closure}}>, main::{{closure}}>";
+/// assert_eq!(abbreviate(name, Abbreviation::depth(1)), "Map<Filter<…>, main::{{closure}}>");
+/// assert_eq!(abbreviate(name, Abbreviation::width(40)), "Map<Filter<…>, main::{{closure}}>");
+/// assert_eq!(abbreviate(name, Abbreviation::width(20)), "Map<Filter<…>, …>");
+/// assert_eq!(abbreviate(name, Abbreviation::width(1)), "Map<…>");
+/// ```
+pub fn abbreviate(name: &str, limits: Abbreviation) -> Cow<'_, str> {
+    let too_wide = |s: &str| limits.max_width.is_some_and(|w| s.chars().count() > w);
+    if limits.max_depth.is_none() && !too_wide(name) {
+        return Cow::Borrowed(name);
+    }
+    let mut tree = Tree::parse(name);
+    if let Some(max_depth) = limits.max_depth {
+        for group in &mut tree.groups {
+            if group.depth > max_depth {
+                group.collapsed = true;
+            }
+        }
+    }
+    let mut out = tree.render();
+    if let Some(max_width) = limits.max_width {
+        let width = out.chars().count();
+        if width > max_width {
+            tree.shrink(width, max_width);
+            out = tree.render();
+        }
+    }
+    if out == name {
+        Cow::Borrowed(name)
+    } else {
+        Cow::Owned(out)
+    }
+}
+
+const ELLIPSIS: &str = "…";
+
+/// The brackets of a type name.
+struct Tree<'a> {
+    src: &'a str,
+    groups: Vec<Group>,
+}
+
+/// A pair of brackets, or one argument inside them.
+struct Group {
+    /// Byte range of the contents, not including the brackets or the separating `,`.
+    start: usize,
+    end: usize,
+    /// How deeply the brackets are nested; the outermost are at 1. Arguments have the depth of
+    /// their brackets.
+    depth: usize,
+    is_arg: bool,
+    collapsed: bool,
+}
+
+impl Group {
+    /// How many bytes of whitespace start the contents, which are kept when it is collapsed.
+    fn leading_space(&self, src: &str) -> usize {
+        let contents = &src[self.start..self.end];
+        contents.len() - contents.trim_start().len()
+    }
+}
+
+impl<'a> Tree<'a> {
+    fn parse(src: &'a str) -> Self {
+        let mut groups = vec![];
+        // Indices into `groups` of the open brackets, and their current argument.
+        let mut stack: Vec<(usize, usize)> = vec![];
+        let mut chars = src.char_indices().peekable();
+        let open_arg = |groups: &mut Vec<Group>, start: usize, depth: usize| {
+            groups.push(Group { start, end: start, depth, is_arg: true, collapsed: false });
+            groups.len() - 1
+        };
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '-' if chars.peek().is_some_and(|&(_, c)| c == '>') => {
+                    chars.next();
+                }
+                '<' | '(' | '[' => {
+                    let depth = stack.len() + 1;
+                    groups.push(Group { start: i + 1, end: i + 1, depth, is_arg: false, collapsed: false });
+                    let bracket = groups.len() - 1;
+                    let arg = open_arg(&mut groups, i + 1, depth);
+                    stack.push((bracket, arg));
+                }
+                ',' if !stack.is_empty() => {
+                    let (bracket, arg) = stack.last_mut().unwrap();
+                    groups[*arg].end = i;
+                    let depth = groups[*bracket].depth;
+                    *arg = open_arg(&mut groups, i + 1, depth);
+                }
+                '>' | ')' | ']' => {
+                    if let Some((bracket, arg)) = stack.pop() {
+                        groups[arg].end = i;
+                        groups[bracket].end = i;
+                    }
+                }
+                _ => (),
+            }
+        }
+        // Groups that are empty or only whitespace, like `()` or after the `,` of `(u8,)`, can't be
+        // shortened.
+        groups.retain(|g| !src[g.start..g.end].trim().is_empty());
+        Tree { src, groups }
+    }
+
+    /// Collapses groups, in the order described on [`abbreviate`], until the name is no more than
+    /// `max_width` `char`s long or nothing else can be collapsed. `width` is how long it is now.
+    ///
+    /// Every group is visited once, from the deepest out, as the widths of the groups at one depth
+    /// only change when deeper groups are collapsed.
+    fn shrink(&mut self, mut width: usize, max_width: usize) {
+        let (src, groups) = (self.src, &mut self.groups);
+        // How many `char`s come before each byte that starts a `char`.
+        let mut chars_before = vec![0; src.len() + 1];
+        for (n, (i, _)) in src.char_indices().enumerate() {
+            chars_before[i] = n;
+        }
+        chars_before[src.len()] = src.chars().count();
+        let chars = |start: usize, end: usize| chars_before[end] - chars_before[start];
+        // How many `char`s each group is shortened by, including the groups inside it.
+        let mut saved = vec![0; groups.len()];
+        let mut parents = vec![None; groups.len()];
+        let mut stack: Vec<usize> = vec![];
+        for (i, g) in groups.iter().enumerate() {
+            while stack.last().is_some_and(|&o| groups[o].end < g.end) {
+                stack.pop();
+            }
+            parents[i] = stack.last().copied();
+            stack.push(i);
+        }
+        // Deepest first, and the arguments at a depth before their brackets.
+        let mut order: Vec<_> = groups.iter().enumerate().map(|(i, g)| (Reverse(g.depth), !g.is_arg, i)).collect();
+        order.sort_unstable();
+        for level in order.chunk_by(|a, b| (a.0, a.1) == (b.0, b.1)) {
+            let (Reverse(depth), is_bracket, _) = level[0];
+            // Only the arguments of the outermost types are collapsed, besides brackets.
+            if depth == 1 || is_bracket {
+                let mut candidates: Vec<(usize, usize)> = level
+                    .iter()
+                    .map(|&(_, _, i)| (chars(groups[i].start, groups[i].end) - saved[i], i))
+                    .filter(|&(w, i)| w > 1 && !groups[i].collapsed)
+                    .collect();
+                // The widest first.
+                candidates.sort_unstable_by(|a, b| b.cmp(a));
+                for (w, i) in candidates {
+                    if width <= max_width {
+                        break;
+                    }
+                    let g = &mut groups[i];
+                    g.collapsed = true;
+                    width -= w - 1 - chars(g.start, g.start + g.leading_space(src));
+                }
+            }
+            for &(_, _, i) in level {
+                let g = &groups[i];
+                if g.collapsed {
+                    saved[i] = chars(g.start + g.leading_space(src), g.end) - 1;
+                }
+                if let Some(parent) = parents[i] {
+                    saved[parent] += saved[i];
+                }
+            }
+        }
+    }
+
+    fn render(&self) -> String {
+        let mut out = String::with_capacity(self.src.len());
+        let mut pos = 0;
+        for g in &self.groups {
+            if !g.collapsed || g.start < pos {
+                continue;
+            }
+            out.push_str(&self.src[pos..g.start]);
+            // Keep the space after a `,`.
+            out.push_str(&self.src[g.start..g.start + g.leading_space(self.src)]);
+            out.push_str(ELLIPSIS);
+            pos = g.end;
+        }
+        out.push_str(&self.src[pos..]);
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{abbreviate, Abbreviation};
+
+    #[test]
+    fn depth() {
+        let name = "Option<Vec<(u8, HashMap<u8, Box<u8>>)>>";
+        assert_eq!(abbreviate(name, Abbreviation::depth(0)), "Option<…>");
+        assert_eq!(abbreviate(name, Abbreviation::depth(1)), "Option<Vec<…>>");
+        assert_eq!(abbreviate(name, Abbreviation::depth(3)), "Option<Vec<(u8, HashMap<…>)>>");
+        assert_eq!(abbreviate(name, Abbreviation::depth(9)), name);
+    }
+
+    #[test]
+    fn width() {
+        let name = "HashMap<String, Vec<Option<u64>>>";
+        assert_eq!(abbreviate(name, Abbreviation::width(99)), name);
+        assert_eq!(abbreviate(name, Abbreviation::width(32)), "HashMap<String, Vec<Option<…>>>");
+        assert_eq!(abbreviate(name, Abbreviation::width(30)), "HashMap<String, Vec<…>>");
+        assert_eq!(abbreviate(name, Abbreviation::width(20)), "HashMap<String, …>");
+        assert_eq!(abbreviate(name, Abbreviation::width(15)), "HashMap<…, …>");
+        assert_eq!(abbreviate(name, Abbreviation::width(12)), "HashMap<…>");
+        assert_eq!(abbreviate(name, Abbreviation::width(0)), "HashMap<…>");
+    }
+
+    #[test]
+    fn other_brackets() {
+        assert_eq!(abbreviate("fn(Vec<u8>) -> Vec<u8>", Abbreviation::depth(1)), "fn(Vec<…>) -> Vec<u8>");
+        assert_eq!(abbreviate("(u8, u8)", Abbreviation::width(7)), "(u8, …)");
+        assert_eq!(abbreviate("(u8,)", Abbreviation::width(1)), "(…)");
+        assert_eq!(abbreviate("&[Vec<u8>; 3]", Abbreviation::depth(1)), "&[Vec<…>; 3]");
+        assert_eq!(abbreviate("u8", Abbreviation::width(1)), "u8");
+    }
+
+    #[test]
+    fn empty_brackets() {
+        let both = Abbreviation { max_depth: Some(2), max_width: Some(5) };
+        assert_eq!(abbreviate("Option<Vec<fn()>>", both), "Option<…>");
+        assert_eq!(abbreviate("Option<Vec<fn()>>", Abbreviation::depth(2)), "Option<Vec<fn()>>");
+        assert_eq!(abbreviate("Vec<Option<()>>", Abbreviation::depth(2)), "Vec<Option<()>>");
+        assert_eq!(abbreviate("Vec<Option<()>>", Abbreviation::depth(1)), "Vec<Option<…>>");
+        assert_eq!(abbreviate("fn() -> ()", Abbreviation::width(1)), "fn() -> ()");
+    }
+
+    #[test]
+    fn long_names() {
+        // Collapsing used to rescan every group for each group, for each step.
+        let mut name = "u8".to_owned();
+        while name.len() < 20_000 {
+            name = format!("Chain<(Map<Filter<{name}, main::{{{{closure}}}}>, F>, vec::IntoIter<u8>)>, Zip<A, B>");
+        }
+        assert_eq!(abbreviate(&name, Abbreviation::width(40)), "Chain<(…)>, Zip<A, B>");
+        assert_eq!(abbreviate(&name, Abbreviation::width(60)), "Chain<(Map<Filter<…>, F>, vec::IntoIter<u8>)>, Zip<A, B>");
+        let deep = format!("{}u8{}", "Vec<".repeat(5_000), ">".repeat(5_000));
+        assert_eq!(abbreviate(&deep, Abbreviation::width(10)), "Vec<…>");
+        assert_eq!(abbreviate(&deep, Abbreviation::width(12)), "Vec<Vec<…>>");
+    }
+}
@@ -0,0 +1,110 @@
+use std::any::type_name;
+use crate::parse::{GenericArg, GenericArgs, ParseError, PathSegment, TypeName, TypePath};
+
+/// Stands for a generic parameter of a type alias. See [`PrettyRules::alias`](crate::PrettyRules::alias).
+///
+/// `Param<0>` is the alias's first parameter, `Param<1>` its second, and so on.
+pub struct Param<const N: usize>;
+
+/// Names that match `expansion` are written as `name`, with the types bound to each [`Param`] as
+/// its generic arguments.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Alias {
+    expansion: TypeName,
+    name: Vec<String>,
+}
+
+impl Alias {
+    pub(crate) fn new(expansion: &str, name: &str) -> Result<Alias, ParseError> {
+        Ok(Alias {
+            expansion: TypeName::parse(expansion)?,
+            name: name.split("::").map(|s| s.trim().to_owned()).collect(),
+        })
+    }
+
+    /// Replaces `ty` with the alias if it matches. Returns `true` if it did.
+    pub(crate) fn apply(&self, ty: &mut TypeName) -> bool {
+        let mut params = vec![];
+        if !unify(&self.expansion, ty, &mut params) {
+            return false;
+        }
+        let mut segments: Vec<PathSegment> = self
+            .name
+            .iter()
+            .map(|name| PathSegment { name: name.clone(), args: GenericArgs::None })
+            .collect();
+        if let Some(last) = segments.last_mut().filter(|_| !params.is_empty()) {
+            let args = params.into_iter().map(|p| GenericArg::Type(p.unwrap_or(TypeName::Infer)));
+            last.args = GenericArgs::AngleBracketed(args.collect());
+        }
+        *ty = TypeName::Path(TypePath { segments });
+        true
+    }
+}
+
+/// Returns `N` if `ty` is a `Param<N>`.
+fn param_index(ty: &TypeName) -> Option<usize> {
+    let param = type_name::<Param<0>>();
+    let param = &param[..param.find('<')?];
+    let path = ty.path()?;
+    if path.segments.len() != param.split("::").count()
+        || !path.segments.iter().zip(param.split("::")).all(|(s, name)| s.name == name)
+    {
+        return None;
+    }
+    match path.generic_args() {
+        [GenericArg::Const(n)] => n.parse().ok(),
+        _ => None,
+    }
+}
+
+/// Does `ty` match `pattern`? The types that match each `Param<N>` are put in `params[N]`; a
+/// `Param` that appears twice must match the same type both times.
+fn unify(pattern: &TypeName, ty: &TypeName, params: &mut Vec<Option<TypeName>>) -> bool {
+    if let Some(i) = param_index(pattern) {
+        if params.len() <= i {
+            params.resize(i + 1, None);
+        }
+        return match &params[i] {
+            Some(bound) => bound == ty,
+            None => {
+                params[i] = Some(ty.clone());
+                true
+            }
+        };
+    }
+    pattern.same_shape(ty)
+        && pattern.children().into_iter().zip(ty.children()).all(|(p, t)| unify(p, t, params))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Alias, Param};
+    use crate::TypeName;
+    use std::any::type_name;
+
+    fn alias<E: ?Sized>(name: &str, ty: &str) -> String {
+        let alias = Alias::new(type_name::<E>(), name).unwrap();
+        let mut ty = TypeName::parse(ty).unwrap();
+        ty.for_each_type_mut(&mut |ty| {
+            alias.apply(ty);
+        });
+        ty.to_string()
+    }
+
+    #[test]
+    fn params() {
+        type Pair<T> = (T, T);
+        assert_eq!(alias::<Pair<Param<0>>>("Pair", "(u8, u8)"), "Pair<u8>");
+        assert_eq!(alias::<Pair<Param<0>>>("Pair", "(u8, i8)"), "(u8, i8)");
+        assert_eq!(alias::<Pair<Param<0>>>("Pair", "[((u8, u8), (u8, u8)); 2]"), "[Pair<Pair<u8>>; 2]");
+        type Swap<A, B> = Result<B, A>;
+        assert_eq!(
+            alias::<Swap<Param<0>, Param<1>>>("m::Swap", "core::result::Result<u8, ()>"),
+            "m::Swap<(), u8>",
+        );
+        assert_eq!(alias::<fn(Param<0>) -> u8>("Cb", "fn(&str) -> u8"), "Cb<&str>");
+        assert_eq!(alias::<Box<dyn Fn(Param<0>)>>("BoxFn", "alloc::boxed::Box<dyn core::ops::function::Fn(u8)>"), "BoxFn<u8>");
+        assert_eq!(alias::<Vec<u8>>("Bytes", "alloc::vec::Vec<u8>"), "Bytes");
+    }
+}
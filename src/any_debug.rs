@@ -11,7 +11,12 @@ pub trait AnyDebug: mopa::Any + fmt::Debug + Send + Sync {
     /// How do you deal with this? Instead of `this.get_ty()`, call `(*this).get_ty()`.
     fn get_ty(&self) -> Ty;
 }
-mopafy!(AnyDebug);
+// mopa's downcasts transmute `*mut ()` into references.
+#[allow(clippy::transmute_ptr_to_ref)]
+mod mopafied {
+    use super::AnyDebug;
+    mopafy!(AnyDebug);
+}
 impl<X: mopa::Any + fmt::Debug + Send + Sync> AnyDebug for X {
     fn get_ty(&self) -> Ty {
         Ty::of::<Self>()
@@ -49,6 +54,17 @@ mod test {
         assert_eq!(ty, (*f).get_ty());
     }
 
+    #[test]
+    fn names() {
+        assert_eq!(format!("{:?}", Ty::of::<Box<dyn AnyDebug>>()), "Box<dyn AnyDebug>");
+        assert_eq!(format!("{:#?}", Ty::of::<Box<dyn AnyDebug>>()), "alloc::boxed::Box<dyn ezty::any_debug::AnyDebug>");
+        assert_eq!(Ty::of::<&dyn AnyDebug>().name(), "&dyn AnyDebug");
+        assert_eq!(Ty::of::<Vec<Box<dyn AnyDebug + Unpin>>>().name(), "Vec<Box<dyn AnyDebug + Unpin>>");
+        assert_eq!(Ty::of::<std::sync::Arc<dyn AnyDebug + 'static>>().name(), "Arc<dyn AnyDebug>");
+        let f: Box<dyn AnyDebug> = Box::new(Box::new(0u8) as Box<dyn AnyDebug>);
+        assert_eq!(f.type_name(), "Box<dyn AnyDebug>");
+    }
+
     #[test]
     fn what_about_std_just_outta_curiosity() {
         use std::any::{TypeId, Any};
@@ -0,0 +1,88 @@
+//! Copies text from stdin, or from the files named as arguments, to stdout with the Rust type
+//! names in it prettified. See [`ezty::prettify_text`].
+//!
+//! ```text
+//! RUST_BACKTRACE=1 cargo run 2>&1 | ezty-pretty
+//! ezty-pretty --style short perf.txt
+//! ```
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Write};
+use std::process::ExitCode;
+use ezty::TyNameStyle;
+
+const USAGE: &str = "\
+Usage: ezty-pretty [--style STYLE] [FILE]...
+
+Prettifies the Rust type names in each FILE, or stdin, and writes the result to stdout.
+
+Options:
+  -s, --style STYLE  full, pretty, expanded, short, canonical or crate=NAME
+                     [default: $EZTY_TY_NAME_STYLE, or pretty]
+  -h, --help         Print this message";
+
+fn main() -> ExitCode {
+    let mut style = TyNameStyle::default_style();
+    let mut paths = vec![];
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return ExitCode::SUCCESS;
+            }
+            "-s" | "--style" => {
+                let Some(name) = args.next() else {
+                    eprintln!("ezty-pretty: {arg} needs a value\n\n{USAGE}");
+                    return ExitCode::FAILURE;
+                };
+                match name.parse() {
+                    Ok(s) => style = s,
+                    Err(e) => {
+                        eprintln!("ezty-pretty: {e}");
+                        return ExitCode::FAILURE;
+                    }
+                }
+            }
+            _ => paths.push(arg),
+        }
+    }
+
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    if paths.is_empty() {
+        return report("stdin", copy(style, io::stdin().lock(), &mut out));
+    }
+    let mut status = ExitCode::SUCCESS;
+    for path in &paths {
+        let result = File::open(path).and_then(|file| copy(style, BufReader::new(file), &mut out));
+        if report(path, result) == ExitCode::FAILURE {
+            status = ExitCode::FAILURE;
+        }
+    }
+    status
+}
+
+/// Copies `input` to `out` a line at a time, so that output keeps up with a running program.
+/// Invalid UTF-8 is replaced with `�` rather than stopping the copy.
+fn copy(style: TyNameStyle, mut input: impl BufRead, out: &mut impl Write) -> io::Result<()> {
+    let mut line = vec![];
+    while input.read_until(b'\n', &mut line)? != 0 {
+        out.write_all(style.render_text(&String::from_utf8_lossy(&line)).as_bytes())?;
+        out.flush()?;
+        line.clear();
+    }
+    Ok(())
+}
+
+fn report(name: &str, result: io::Result<()>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        // Piped into `head`, say.
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("ezty-pretty: {name}: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,452 @@
+&[
+    // The public re-export of every std, core & alloc type that is stable as of Rust 1.95, and whose
+    // `type_name` differs from it. The paths that older compilers gave some of them, eg
+    // `std::sync::mutex::Mutex`, are here too. Derived from the std docs; each entry is
+    // (`type_name` path, public path).
+    //
+    // Fingerprints hash the canonical names, so a change here that renames a type must come with
+    // a new `TyFingerprint::VERSION`.
+    ("alloc::borrow::Cow", "std::borrow::Cow"),
+    ("alloc::borrow::ToOwned", "std::borrow::ToOwned"),
+    ("alloc::boxed::Box", "std::boxed::Box"),
+    ("alloc::collections::TryReserveError", "std::collections::TryReserveError"),
+    ("alloc::collections::binary_heap::BinaryHeap", "std::collections::BinaryHeap"),
+    ("alloc::collections::binary_heap::Drain", "std::collections::binary_heap::Drain"),
+    ("alloc::collections::binary_heap::IntoIter", "std::collections::binary_heap::IntoIter"),
+    ("alloc::collections::binary_heap::Iter", "std::collections::binary_heap::Iter"),
+    ("alloc::collections::binary_heap::PeekMut", "std::collections::binary_heap::PeekMut"),
+    ("alloc::collections::btree::map::BTreeMap", "std::collections::BTreeMap"),
+    ("alloc::collections::btree::map::ExtractIf", "std::collections::btree_map::ExtractIf"),
+    ("alloc::collections::btree::map::IntoIter", "std::collections::btree_map::IntoIter"),
+    ("alloc::collections::btree::map::IntoKeys", "std::collections::btree_map::IntoKeys"),
+    ("alloc::collections::btree::map::IntoValues", "std::collections::btree_map::IntoValues"),
+    ("alloc::collections::btree::map::Iter", "std::collections::btree_map::Iter"),
+    ("alloc::collections::btree::map::IterMut", "std::collections::btree_map::IterMut"),
+    ("alloc::collections::btree::map::Keys", "std::collections::btree_map::Keys"),
+    ("alloc::collections::btree::map::Range", "std::collections::btree_map::Range"),
+    ("alloc::collections::btree::map::RangeMut", "std::collections::btree_map::RangeMut"),
+    ("alloc::collections::btree::map::Values", "std::collections::btree_map::Values"),
+    ("alloc::collections::btree::map::ValuesMut", "std::collections::btree_map::ValuesMut"),
+    ("alloc::collections::btree::map::entry::Entry", "std::collections::btree_map::Entry"),
+    ("alloc::collections::btree::map::entry::OccupiedEntry", "std::collections::btree_map::OccupiedEntry"),
+    ("alloc::collections::btree::map::entry::VacantEntry", "std::collections::btree_map::VacantEntry"),
+    ("alloc::collections::btree::set::BTreeSet", "std::collections::BTreeSet"),
+    ("alloc::collections::btree::set::Difference", "std::collections::btree_set::Difference"),
+    ("alloc::collections::btree::set::ExtractIf", "std::collections::btree_set::ExtractIf"),
+    ("alloc::collections::btree::set::Intersection", "std::collections::btree_set::Intersection"),
+    ("alloc::collections::btree::set::IntoIter", "std::collections::btree_set::IntoIter"),
+    ("alloc::collections::btree::set::Iter", "std::collections::btree_set::Iter"),
+    ("alloc::collections::btree::set::Range", "std::collections::btree_set::Range"),
+    ("alloc::collections::btree::set::SymmetricDifference", "std::collections::btree_set::SymmetricDifference"),
+    ("alloc::collections::btree::set::Union", "std::collections::btree_set::Union"),
+    ("alloc::collections::linked_list::ExtractIf", "std::collections::linked_list::ExtractIf"),
+    ("alloc::collections::linked_list::IntoIter", "std::collections::linked_list::IntoIter"),
+    ("alloc::collections::linked_list::Iter", "std::collections::linked_list::Iter"),
+    ("alloc::collections::linked_list::IterMut", "std::collections::linked_list::IterMut"),
+    ("alloc::collections::linked_list::LinkedList", "std::collections::LinkedList"),
+    ("alloc::collections::vec_deque::VecDeque", "std::collections::VecDeque"),
+    ("alloc::collections::vec_deque::drain::Drain", "std::collections::vec_deque::Drain"),
+    ("alloc::collections::vec_deque::into_iter::IntoIter", "std::collections::vec_deque::IntoIter"),
+    ("alloc::collections::vec_deque::iter::Iter", "std::collections::vec_deque::Iter"),
+    ("alloc::collections::vec_deque::iter_mut::IterMut", "std::collections::vec_deque::IterMut"),
+    ("alloc::ffi::c_str::CString", "std::ffi::CString"),
+    ("alloc::ffi::c_str::FromVecWithNulError", "std::ffi::FromVecWithNulError"),
+    ("alloc::ffi::c_str::IntoStringError", "std::ffi::IntoStringError"),
+    ("alloc::ffi::c_str::NulError", "std::ffi::NulError"),
+    ("alloc::rc::Rc", "std::rc::Rc"),
+    ("alloc::rc::Weak", "std::rc::Weak"),
+    ("alloc::string::Drain", "std::string::Drain"),
+    ("alloc::string::FromUtf16Error", "std::string::FromUtf16Error"),
+    ("alloc::string::FromUtf8Error", "std::string::FromUtf8Error"),
+    ("alloc::string::String", "std::string::String"),
+    ("alloc::string::ToString", "std::string::ToString"),
+    ("alloc::sync::Arc", "std::sync::Arc"),
+    ("alloc::sync::Weak", "std::sync::Weak"),
+    ("alloc::task::Wake", "std::task::Wake"),
+    ("alloc::vec::Vec", "std::vec::Vec"),
+    ("alloc::vec::drain::Drain", "std::vec::Drain"),
+    ("alloc::vec::extract_if::ExtractIf", "std::vec::ExtractIf"),
+    ("alloc::vec::into_iter::IntoIter", "std::vec::IntoIter"),
+    ("alloc::vec::splice::Splice", "std::vec::Splice"),
+    ("core::alloc::global::GlobalAlloc", "std::alloc::GlobalAlloc"),
+    ("core::alloc::layout::Layout", "std::alloc::Layout"),
+    ("core::alloc::layout::LayoutError", "std::alloc::LayoutError"),
+    ("core::any::Any", "std::any::Any"),
+    ("core::any::TypeId", "std::any::TypeId"),
+    ("core::array::TryFromSliceError", "std::array::TryFromSliceError"),
+    ("core::array::iter::IntoIter", "std::array::IntoIter"),
+    ("core::ascii::EscapeDefault", "std::ascii::EscapeDefault"),
+    ("core::borrow::Borrow", "std::borrow::Borrow"),
+    ("core::borrow::BorrowMut", "std::borrow::BorrowMut"),
+    ("core::cell::BorrowError", "std::cell::BorrowError"),
+    ("core::cell::BorrowMutError", "std::cell::BorrowMutError"),
+    ("core::cell::Cell", "std::cell::Cell"),
+    ("core::cell::Ref", "std::cell::Ref"),
+    ("core::cell::RefCell", "std::cell::RefCell"),
+    ("core::cell::RefMut", "std::cell::RefMut"),
+    ("core::cell::UnsafeCell", "std::cell::UnsafeCell"),
+    ("core::cell::lazy::LazyCell", "std::cell::LazyCell"),
+    ("core::cell::once::OnceCell", "std::cell::OnceCell"),
+    ("core::char::EscapeDebug", "std::char::EscapeDebug"),
+    ("core::char::EscapeDefault", "std::char::EscapeDefault"),
+    ("core::char::EscapeUnicode", "std::char::EscapeUnicode"),
+    ("core::char::ToLowercase", "std::char::ToLowercase"),
+    ("core::char::ToUppercase", "std::char::ToUppercase"),
+    ("core::char::TryFromCharError", "std::char::TryFromCharError"),
+    ("core::char::convert::CharTryFromError", "std::char::CharTryFromError"),
+    ("core::char::convert::ParseCharError", "std::char::ParseCharError"),
+    ("core::char::decode::DecodeUtf16", "std::char::DecodeUtf16"),
+    ("core::char::decode::DecodeUtf16Error", "std::char::DecodeUtf16Error"),
+    ("core::clone::Clone", "std::clone::Clone"),
+    ("core::cmp::Eq", "std::cmp::Eq"),
+    ("core::cmp::Ord", "std::cmp::Ord"),
+    ("core::cmp::Ordering", "std::cmp::Ordering"),
+    ("core::cmp::PartialEq", "std::cmp::PartialEq"),
+    ("core::cmp::PartialOrd", "std::cmp::PartialOrd"),
+    ("core::cmp::Reverse", "std::cmp::Reverse"),
+    ("core::convert::AsMut", "std::convert::AsMut"),
+    ("core::convert::AsRef", "std::convert::AsRef"),
+    ("core::convert::From", "std::convert::From"),
+    ("core::convert::Infallible", "std::convert::Infallible"),
+    ("core::convert::Into", "std::convert::Into"),
+    ("core::convert::TryFrom", "std::convert::TryFrom"),
+    ("core::convert::TryInto", "std::convert::TryInto"),
+    ("core::default::Default", "std::default::Default"),
+    ("core::error::Error", "std::error::Error"),
+    ("core::ffi::c_str::CStr", "std::ffi::CStr"),
+    ("core::ffi::c_str::FromBytesUntilNulError", "std::ffi::FromBytesUntilNulError"),
+    ("core::ffi::c_str::FromBytesWithNulError", "std::ffi::FromBytesWithNulError"),
+    ("core::ffi::c_void", "std::ffi::c_void"),
+    ("core::fmt::Alignment", "std::fmt::Alignment"),
+    ("core::fmt::Arguments", "std::fmt::Arguments"),
+    ("core::fmt::Binary", "std::fmt::Binary"),
+    ("core::fmt::Debug", "std::fmt::Debug"),
+    ("core::fmt::Display", "std::fmt::Display"),
+    ("core::fmt::Error", "std::fmt::Error"),
+    ("core::fmt::Formatter", "std::fmt::Formatter"),
+    ("core::fmt::LowerExp", "std::fmt::LowerExp"),
+    ("core::fmt::LowerHex", "std::fmt::LowerHex"),
+    ("core::fmt::Octal", "std::fmt::Octal"),
+    ("core::fmt::Pointer", "std::fmt::Pointer"),
+    ("core::fmt::UpperExp", "std::fmt::UpperExp"),
+    ("core::fmt::UpperHex", "std::fmt::UpperHex"),
+    ("core::fmt::Write", "std::fmt::Write"),
+    ("core::fmt::builders::DebugList", "std::fmt::DebugList"),
+    ("core::fmt::builders::DebugMap", "std::fmt::DebugMap"),
+    ("core::fmt::builders::DebugSet", "std::fmt::DebugSet"),
+    ("core::fmt::builders::DebugStruct", "std::fmt::DebugStruct"),
+    ("core::fmt::builders::DebugTuple", "std::fmt::DebugTuple"),
+    ("core::fmt::builders::FromFn", "std::fmt::FromFn"),
+    ("core::future::future::Future", "std::future::Future"),
+    ("core::future::into_future::IntoFuture", "std::future::IntoFuture"),
+    ("core::future::pending::Pending", "std::future::Pending"),
+    ("core::future::poll_fn::PollFn", "std::future::PollFn"),
+    ("core::future::ready::Ready", "std::future::Ready"),
+    ("core::hash::BuildHasher", "std::hash::BuildHasher"),
+    ("core::hash::BuildHasherDefault", "std::hash::BuildHasherDefault"),
+    ("core::hash::Hash", "std::hash::Hash"),
+    ("core::hash::Hasher", "std::hash::Hasher"),
+    ("core::hash::sip::SipHasher", "std::hash::SipHasher"),
+    ("core::iter::adapters::chain::Chain", "std::iter::Chain"),
+    ("core::iter::adapters::cloned::Cloned", "std::iter::Cloned"),
+    ("core::iter::adapters::copied::Copied", "std::iter::Copied"),
+    ("core::iter::adapters::cycle::Cycle", "std::iter::Cycle"),
+    ("core::iter::adapters::enumerate::Enumerate", "std::iter::Enumerate"),
+    ("core::iter::adapters::filter::Filter", "std::iter::Filter"),
+    ("core::iter::adapters::filter_map::FilterMap", "std::iter::FilterMap"),
+    ("core::iter::adapters::flatten::FlatMap", "std::iter::FlatMap"),
+    ("core::iter::adapters::flatten::Flatten", "std::iter::Flatten"),
+    ("core::iter::adapters::fuse::Fuse", "std::iter::Fuse"),
+    ("core::iter::adapters::inspect::Inspect", "std::iter::Inspect"),
+    ("core::iter::adapters::map::Map", "std::iter::Map"),
+    ("core::iter::adapters::map_while::MapWhile", "std::iter::MapWhile"),
+    ("core::iter::adapters::peekable::Peekable", "std::iter::Peekable"),
+    ("core::iter::adapters::rev::Rev", "std::iter::Rev"),
+    ("core::iter::adapters::scan::Scan", "std::iter::Scan"),
+    ("core::iter::adapters::skip::Skip", "std::iter::Skip"),
+    ("core::iter::adapters::skip_while::SkipWhile", "std::iter::SkipWhile"),
+    ("core::iter::adapters::step_by::StepBy", "std::iter::StepBy"),
+    ("core::iter::adapters::take::Take", "std::iter::Take"),
+    ("core::iter::adapters::take_while::TakeWhile", "std::iter::TakeWhile"),
+    ("core::iter::adapters::zip::Zip", "std::iter::Zip"),
+    ("core::iter::sources::empty::Empty", "std::iter::Empty"),
+    ("core::iter::sources::from_fn::FromFn", "std::iter::FromFn"),
+    ("core::iter::sources::once::Once", "std::iter::Once"),
+    ("core::iter::sources::once_with::OnceWith", "std::iter::OnceWith"),
+    ("core::iter::sources::repeat::Repeat", "std::iter::Repeat"),
+    ("core::iter::sources::repeat_n::RepeatN", "std::iter::RepeatN"),
+    ("core::iter::sources::repeat_with::RepeatWith", "std::iter::RepeatWith"),
+    ("core::iter::sources::successors::Successors", "std::iter::Successors"),
+    ("core::iter::traits::accum::Product", "std::iter::Product"),
+    ("core::iter::traits::accum::Sum", "std::iter::Sum"),
+    ("core::iter::traits::collect::Extend", "std::iter::Extend"),
+    ("core::iter::traits::collect::FromIterator", "std::iter::FromIterator"),
+    ("core::iter::traits::collect::IntoIterator", "std::iter::IntoIterator"),
+    ("core::iter::traits::double_ended::DoubleEndedIterator", "std::iter::DoubleEndedIterator"),
+    ("core::iter::traits::exact_size::ExactSizeIterator", "std::iter::ExactSizeIterator"),
+    ("core::iter::traits::iterator::Iterator", "std::iter::Iterator"),
+    ("core::iter::traits::marker::FusedIterator", "std::iter::FusedIterator"),
+    ("core::marker::Copy", "std::marker::Copy"),
+    ("core::marker::PhantomData", "std::marker::PhantomData"),
+    ("core::marker::PhantomPinned", "std::marker::PhantomPinned"),
+    ("core::marker::Send", "std::marker::Send"),
+    ("core::marker::Sized", "std::marker::Sized"),
+    ("core::marker::Sync", "std::marker::Sync"),
+    ("core::marker::Unpin", "std::marker::Unpin"),
+    ("core::mem::Discriminant", "std::mem::Discriminant"),
+    ("core::mem::manually_drop::ManuallyDrop", "std::mem::ManuallyDrop"),
+    ("core::mem::maybe_uninit::MaybeUninit", "std::mem::MaybeUninit"),
+    ("core::net::ip_addr::IpAddr", "std::net::IpAddr"),
+    ("core::net::ip_addr::Ipv4Addr", "std::net::Ipv4Addr"),
+    ("core::net::ip_addr::Ipv6Addr", "std::net::Ipv6Addr"),
+    ("core::net::parser::AddrParseError", "std::net::AddrParseError"),
+    ("core::net::socket_addr::SocketAddr", "std::net::SocketAddr"),
+    ("core::net::socket_addr::SocketAddrV4", "std::net::SocketAddrV4"),
+    ("core::net::socket_addr::SocketAddrV6", "std::net::SocketAddrV6"),
+    ("core::num::FpCategory", "std::num::FpCategory"),
+    ("core::num::dec2flt::ParseFloatError", "std::num::ParseFloatError"),
+    ("core::num::error::IntErrorKind", "std::num::IntErrorKind"),
+    ("core::num::error::ParseIntError", "std::num::ParseIntError"),
+    ("core::num::error::TryFromIntError", "std::num::TryFromIntError"),
+    ("core::num::nonzero::NonZero", "std::num::NonZero"),
+    ("core::num::nonzero::NonZeroI128", "std::num::NonZeroI128"),
+    ("core::num::nonzero::NonZeroI16", "std::num::NonZeroI16"),
+    ("core::num::nonzero::NonZeroI32", "std::num::NonZeroI32"),
+    ("core::num::nonzero::NonZeroI64", "std::num::NonZeroI64"),
+    ("core::num::nonzero::NonZeroI8", "std::num::NonZeroI8"),
+    ("core::num::nonzero::NonZeroIsize", "std::num::NonZeroIsize"),
+    ("core::num::nonzero::NonZeroU128", "std::num::NonZeroU128"),
+    ("core::num::nonzero::NonZeroU16", "std::num::NonZeroU16"),
+    ("core::num::nonzero::NonZeroU32", "std::num::NonZeroU32"),
+    ("core::num::nonzero::NonZeroU64", "std::num::NonZeroU64"),
+    ("core::num::nonzero::NonZeroU8", "std::num::NonZeroU8"),
+    ("core::num::nonzero::NonZeroUsize", "std::num::NonZeroUsize"),
+    ("core::num::saturating::Saturating", "std::num::Saturating"),
+    ("core::num::wrapping::Wrapping", "std::num::Wrapping"),
+    ("core::ops::arith::Add", "std::ops::Add"),
+    ("core::ops::arith::AddAssign", "std::ops::AddAssign"),
+    ("core::ops::arith::Div", "std::ops::Div"),
+    ("core::ops::arith::DivAssign", "std::ops::DivAssign"),
+    ("core::ops::arith::Mul", "std::ops::Mul"),
+    ("core::ops::arith::MulAssign", "std::ops::MulAssign"),
+    ("core::ops::arith::Neg", "std::ops::Neg"),
+    ("core::ops::arith::Rem", "std::ops::Rem"),
+    ("core::ops::arith::RemAssign", "std::ops::RemAssign"),
+    ("core::ops::arith::Sub", "std::ops::Sub"),
+    ("core::ops::arith::SubAssign", "std::ops::SubAssign"),
+    ("core::ops::bit::BitAnd", "std::ops::BitAnd"),
+    ("core::ops::bit::BitAndAssign", "std::ops::BitAndAssign"),
+    ("core::ops::bit::BitOr", "std::ops::BitOr"),
+    ("core::ops::bit::BitOrAssign", "std::ops::BitOrAssign"),
+    ("core::ops::bit::BitXor", "std::ops::BitXor"),
+    ("core::ops::bit::BitXorAssign", "std::ops::BitXorAssign"),
+    ("core::ops::bit::Not", "std::ops::Not"),
+    ("core::ops::bit::Shl", "std::ops::Shl"),
+    ("core::ops::bit::ShlAssign", "std::ops::ShlAssign"),
+    ("core::ops::bit::Shr", "std::ops::Shr"),
+    ("core::ops::bit::ShrAssign", "std::ops::ShrAssign"),
+    ("core::ops::control_flow::ControlFlow", "std::ops::ControlFlow"),
+    ("core::ops::deref::Deref", "std::ops::Deref"),
+    ("core::ops::deref::DerefMut", "std::ops::DerefMut"),
+    ("core::ops::drop::Drop", "std::ops::Drop"),
+    ("core::ops::function::Fn", "std::ops::Fn"),
+    ("core::ops::function::FnMut", "std::ops::FnMut"),
+    ("core::ops::function::FnOnce", "std::ops::FnOnce"),
+    ("core::ops::index::Index", "std::ops::Index"),
+    ("core::ops::index::IndexMut", "std::ops::IndexMut"),
+    ("core::ops::range::Bound", "std::ops::Bound"),
+    ("core::ops::range::Range", "std::ops::Range"),
+    ("core::ops::range::RangeBounds", "std::ops::RangeBounds"),
+    ("core::ops::range::RangeFrom", "std::ops::RangeFrom"),
+    ("core::ops::range::RangeFull", "std::ops::RangeFull"),
+    ("core::ops::range::RangeInclusive", "std::ops::RangeInclusive"),
+    ("core::ops::range::RangeTo", "std::ops::RangeTo"),
+    ("core::ops::range::RangeToInclusive", "std::ops::RangeToInclusive"),
+    ("core::option::IntoIter", "std::option::IntoIter"),
+    ("core::option::Iter", "std::option::Iter"),
+    ("core::option::IterMut", "std::option::IterMut"),
+    ("core::option::Option", "std::option::Option"),
+    ("core::panic::location::Location", "std::panic::Location"),
+    ("core::panic::unwind_safe::AssertUnwindSafe", "std::panic::AssertUnwindSafe"),
+    ("core::panic::unwind_safe::RefUnwindSafe", "std::panic::RefUnwindSafe"),
+    ("core::panic::unwind_safe::UnwindSafe", "std::panic::UnwindSafe"),
+    ("core::pin::Pin", "std::pin::Pin"),
+    ("core::ptr::non_null::NonNull", "std::ptr::NonNull"),
+    ("core::range::RangeInclusive", "std::range::RangeInclusive"),
+    ("core::range::iter::RangeInclusiveIter", "std::range::RangeInclusiveIter"),
+    ("core::result::IntoIter", "std::result::IntoIter"),
+    ("core::result::Iter", "std::result::Iter"),
+    ("core::result::IterMut", "std::result::IterMut"),
+    ("core::result::Result", "std::result::Result"),
+    ("core::slice::GetDisjointMutError", "std::slice::GetDisjointMutError"),
+    ("core::slice::ascii::EscapeAscii", "std::slice::EscapeAscii"),
+    ("core::slice::index::SliceIndex", "std::slice::SliceIndex"),
+    ("core::slice::iter::ArrayWindows", "std::slice::ArrayWindows"),
+    ("core::slice::iter::ChunkBy", "std::slice::ChunkBy"),
+    ("core::slice::iter::ChunkByMut", "std::slice::ChunkByMut"),
+    ("core::slice::iter::Chunks", "std::slice::Chunks"),
+    ("core::slice::iter::ChunksExact", "std::slice::ChunksExact"),
+    ("core::slice::iter::ChunksExactMut", "std::slice::ChunksExactMut"),
+    ("core::slice::iter::ChunksMut", "std::slice::ChunksMut"),
+    ("core::slice::iter::Iter", "std::slice::Iter"),
+    ("core::slice::iter::IterMut", "std::slice::IterMut"),
+    ("core::slice::iter::RChunks", "std::slice::RChunks"),
+    ("core::slice::iter::RChunksExact", "std::slice::RChunksExact"),
+    ("core::slice::iter::RChunksExactMut", "std::slice::RChunksExactMut"),
+    ("core::slice::iter::RChunksMut", "std::slice::RChunksMut"),
+    ("core::slice::iter::RSplit", "std::slice::RSplit"),
+    ("core::slice::iter::RSplitMut", "std::slice::RSplitMut"),
+    ("core::slice::iter::RSplitN", "std::slice::RSplitN"),
+    ("core::slice::iter::RSplitNMut", "std::slice::RSplitNMut"),
+    ("core::slice::iter::Split", "std::slice::Split"),
+    ("core::slice::iter::SplitInclusive", "std::slice::SplitInclusive"),
+    ("core::slice::iter::SplitInclusiveMut", "std::slice::SplitInclusiveMut"),
+    ("core::slice::iter::SplitMut", "std::slice::SplitMut"),
+    ("core::slice::iter::SplitN", "std::slice::SplitN"),
+    ("core::slice::iter::SplitNMut", "std::slice::SplitNMut"),
+    ("core::slice::iter::Windows", "std::slice::Windows"),
+    ("core::str::error::ParseBoolError", "std::str::ParseBoolError"),
+    ("core::str::error::Utf8Error", "std::str::Utf8Error"),
+    ("core::str::iter::Bytes", "std::str::Bytes"),
+    ("core::str::iter::CharIndices", "std::str::CharIndices"),
+    ("core::str::iter::Chars", "std::str::Chars"),
+    ("core::str::iter::EncodeUtf16", "std::str::EncodeUtf16"),
+    ("core::str::iter::EscapeDebug", "std::str::EscapeDebug"),
+    ("core::str::iter::EscapeDefault", "std::str::EscapeDefault"),
+    ("core::str::iter::EscapeUnicode", "std::str::EscapeUnicode"),
+    ("core::str::iter::Lines", "std::str::Lines"),
+    ("core::str::iter::LinesAny", "std::str::LinesAny"),
+    ("core::str::iter::MatchIndices", "std::str::MatchIndices"),
+    ("core::str::iter::Matches", "std::str::Matches"),
+    ("core::str::iter::RMatchIndices", "std::str::RMatchIndices"),
+    ("core::str::iter::RMatches", "std::str::RMatches"),
+    ("core::str::iter::RSplit", "std::str::RSplit"),
+    ("core::str::iter::RSplitN", "std::str::RSplitN"),
+    ("core::str::iter::RSplitTerminator", "std::str::RSplitTerminator"),
+    ("core::str::iter::Split", "std::str::Split"),
+    ("core::str::iter::SplitAsciiWhitespace", "std::str::SplitAsciiWhitespace"),
+    ("core::str::iter::SplitInclusive", "std::str::SplitInclusive"),
+    ("core::str::iter::SplitN", "std::str::SplitN"),
+    ("core::str::iter::SplitTerminator", "std::str::SplitTerminator"),
+    ("core::str::iter::SplitWhitespace", "std::str::SplitWhitespace"),
+    ("core::str::lossy::Utf8Chunk", "std::str::Utf8Chunk"),
+    ("core::str::lossy::Utf8Chunks", "std::str::Utf8Chunks"),
+    ("core::str::traits::FromStr", "std::str::FromStr"),
+    ("core::sync::atomic::AtomicBool", "std::sync::atomic::AtomicBool"),
+    ("core::sync::atomic::AtomicI16", "std::sync::atomic::AtomicI16"),
+    ("core::sync::atomic::AtomicI32", "std::sync::atomic::AtomicI32"),
+    ("core::sync::atomic::AtomicI64", "std::sync::atomic::AtomicI64"),
+    ("core::sync::atomic::AtomicI8", "std::sync::atomic::AtomicI8"),
+    ("core::sync::atomic::AtomicIsize", "std::sync::atomic::AtomicIsize"),
+    ("core::sync::atomic::AtomicPtr", "std::sync::atomic::AtomicPtr"),
+    ("core::sync::atomic::AtomicU16", "std::sync::atomic::AtomicU16"),
+    ("core::sync::atomic::AtomicU32", "std::sync::atomic::AtomicU32"),
+    ("core::sync::atomic::AtomicU64", "std::sync::atomic::AtomicU64"),
+    ("core::sync::atomic::AtomicU8", "std::sync::atomic::AtomicU8"),
+    ("core::sync::atomic::AtomicUsize", "std::sync::atomic::AtomicUsize"),
+    ("core::sync::atomic::Ordering", "std::sync::atomic::Ordering"),
+    ("core::task::poll::Poll", "std::task::Poll"),
+    ("core::task::wake::Context", "std::task::Context"),
+    ("core::task::wake::RawWaker", "std::task::RawWaker"),
+    ("core::task::wake::RawWakerVTable", "std::task::RawWakerVTable"),
+    ("core::task::wake::Waker", "std::task::Waker"),
+    ("core::time::Duration", "std::time::Duration"),
+    ("core::time::TryFromFloatSecsError", "std::time::TryFromFloatSecsError"),
+    ("std::collections::hash::map::Drain", "std::collections::hash_map::Drain"),
+    ("std::collections::hash::map::Entry", "std::collections::hash_map::Entry"),
+    ("std::collections::hash::map::ExtractIf", "std::collections::hash_map::ExtractIf"),
+    ("std::collections::hash::map::HashMap", "std::collections::HashMap"),
+    ("std::collections::hash::map::IntoIter", "std::collections::hash_map::IntoIter"),
+    ("std::collections::hash::map::IntoKeys", "std::collections::hash_map::IntoKeys"),
+    ("std::collections::hash::map::IntoValues", "std::collections::hash_map::IntoValues"),
+    ("std::collections::hash::map::Iter", "std::collections::hash_map::Iter"),
+    ("std::collections::hash::map::IterMut", "std::collections::hash_map::IterMut"),
+    ("std::collections::hash::map::Keys", "std::collections::hash_map::Keys"),
+    ("std::collections::hash::map::OccupiedEntry", "std::collections::hash_map::OccupiedEntry"),
+    ("std::collections::hash::map::VacantEntry", "std::collections::hash_map::VacantEntry"),
+    ("std::collections::hash::map::Values", "std::collections::hash_map::Values"),
+    ("std::collections::hash::map::ValuesMut", "std::collections::hash_map::ValuesMut"),
+    ("std::collections::hash::set::Difference", "std::collections::hash_set::Difference"),
+    ("std::collections::hash::set::Drain", "std::collections::hash_set::Drain"),
+    ("std::collections::hash::set::ExtractIf", "std::collections::hash_set::ExtractIf"),
+    ("std::collections::hash::set::HashSet", "std::collections::HashSet"),
+    ("std::collections::hash::set::Intersection", "std::collections::hash_set::Intersection"),
+    ("std::collections::hash::set::IntoIter", "std::collections::hash_set::IntoIter"),
+    ("std::collections::hash::set::Iter", "std::collections::hash_set::Iter"),
+    ("std::collections::hash::set::SymmetricDifference", "std::collections::hash_set::SymmetricDifference"),
+    ("std::collections::hash::set::Union", "std::collections::hash_set::Union"),
+    ("std::ffi::os_str::OsStr", "std::ffi::OsStr"),
+    ("std::ffi::os_str::OsString", "std::ffi::OsString"),
+    ("std::hash::random::DefaultHasher", "std::hash::DefaultHasher"),
+    ("std::hash::random::RandomState", "std::hash::RandomState"),
+    ("std::io::buffered::IntoInnerError", "std::io::IntoInnerError"),
+    ("std::io::buffered::bufreader::BufReader", "std::io::BufReader"),
+    ("std::io::buffered::bufwriter::BufWriter", "std::io::BufWriter"),
+    ("std::io::buffered::bufwriter::WriterPanicked", "std::io::WriterPanicked"),
+    ("std::io::buffered::linewriter::LineWriter", "std::io::LineWriter"),
+    ("std::io::cursor::Cursor", "std::io::Cursor"),
+    ("std::io::error::Error", "std::io::Error"),
+    ("std::io::error::ErrorKind", "std::io::ErrorKind"),
+    ("std::io::pipe::PipeReader", "std::io::PipeReader"),
+    ("std::io::pipe::PipeWriter", "std::io::PipeWriter"),
+    ("std::io::stdio::IsTerminal", "std::io::IsTerminal"),
+    ("std::io::stdio::Stderr", "std::io::Stderr"),
+    ("std::io::stdio::StderrLock", "std::io::StderrLock"),
+    ("std::io::stdio::Stdin", "std::io::Stdin"),
+    ("std::io::stdio::StdinLock", "std::io::StdinLock"),
+    ("std::io::stdio::Stdout", "std::io::Stdout"),
+    ("std::io::stdio::StdoutLock", "std::io::StdoutLock"),
+    ("std::io::util::Empty", "std::io::Empty"),
+    ("std::io::util::Repeat", "std::io::Repeat"),
+    ("std::io::util::Sink", "std::io::Sink"),
+    ("std::net::socket_addr::ToSocketAddrs", "std::net::ToSocketAddrs"),
+    ("std::net::tcp::Incoming", "std::net::Incoming"),
+    ("std::net::tcp::TcpListener", "std::net::TcpListener"),
+    ("std::net::tcp::TcpStream", "std::net::TcpStream"),
+    ("std::net::udp::UdpSocket", "std::net::UdpSocket"),
+    ("std::os::fd::owned::AsFd", "std::os::fd::AsFd"),
+    ("std::os::fd::owned::BorrowedFd", "std::os::fd::BorrowedFd"),
+    ("std::os::fd::owned::OwnedFd", "std::os::fd::OwnedFd"),
+    ("std::os::fd::raw::AsRawFd", "std::os::fd::AsRawFd"),
+    ("std::os::fd::raw::FromRawFd", "std::os::fd::FromRawFd"),
+    ("std::os::fd::raw::IntoRawFd", "std::os::fd::IntoRawFd"),
+    ("std::os::linux::raw::arch::stat", "std::os::linux::raw::stat"),
+    ("std::os::net::linux_ext::addr::SocketAddrExt", "std::os::linux::net::SocketAddrExt"),
+    ("std::os::net::linux_ext::tcp::TcpStreamExt", "std::os::linux::net::TcpStreamExt"),
+    ("std::os::unix::ffi::os_str::OsStrExt", "std::os::unix::ffi::OsStrExt"),
+    ("std::os::unix::ffi::os_str::OsStringExt", "std::os::unix::ffi::OsStringExt"),
+    ("std::os::unix::net::addr::SocketAddr", "std::os::unix::net::SocketAddr"),
+    ("std::os::unix::net::datagram::UnixDatagram", "std::os::unix::net::UnixDatagram"),
+    ("std::os::unix::net::listener::Incoming", "std::os::unix::net::Incoming"),
+    ("std::os::unix::net::listener::UnixListener", "std::os::unix::net::UnixListener"),
+    ("std::os::unix::net::stream::UnixStream", "std::os::unix::net::UnixStream"),
+    ("std::sync::barrier::Barrier", "std::sync::Barrier"),
+    ("std::sync::barrier::BarrierWaitResult", "std::sync::BarrierWaitResult"),
+    ("std::sync::condvar::Condvar", "std::sync::Condvar"),
+    ("std::sync::condvar::WaitTimeoutResult", "std::sync::WaitTimeoutResult"),
+    ("std::sync::lazy_lock::LazyLock", "std::sync::LazyLock"),
+    ("std::sync::mutex::Mutex", "std::sync::Mutex"),
+    ("std::sync::mutex::MutexGuard", "std::sync::MutexGuard"),
+    ("std::sync::once::Once", "std::sync::Once"),
+    ("std::sync::once::OnceState", "std::sync::OnceState"),
+    ("std::sync::once_lock::OnceLock", "std::sync::OnceLock"),
+    ("std::sync::poison::PoisonError", "std::sync::PoisonError"),
+    ("std::sync::poison::TryLockError", "std::sync::TryLockError"),
+    ("std::sync::poison::condvar::Condvar", "std::sync::Condvar"),
+    ("std::sync::poison::mutex::Mutex", "std::sync::Mutex"),
+    ("std::sync::poison::mutex::MutexGuard", "std::sync::MutexGuard"),
+    ("std::sync::poison::rwlock::RwLock", "std::sync::RwLock"),
+    ("std::sync::poison::rwlock::RwLockReadGuard", "std::sync::RwLockReadGuard"),
+    ("std::sync::poison::rwlock::RwLockWriteGuard", "std::sync::RwLockWriteGuard"),
+    ("std::sync::rwlock::RwLock", "std::sync::RwLock"),
+    ("std::sync::rwlock::RwLockReadGuard", "std::sync::RwLockReadGuard"),
+    ("std::sync::rwlock::RwLockWriteGuard", "std::sync::RwLockWriteGuard"),
+    ("std::thread::builder::Builder", "std::thread::Builder"),
+    ("std::thread::id::ThreadId", "std::thread::ThreadId"),
+    ("std::thread::join_handle::JoinHandle", "std::thread::JoinHandle"),
+    ("std::thread::local::AccessError", "std::thread::AccessError"),
+    ("std::thread::local::LocalKey", "std::thread::LocalKey"),
+    ("std::thread::scoped::Scope", "std::thread::Scope"),
+    ("std::thread::scoped::ScopedJoinHandle", "std::thread::ScopedJoinHandle"),
+    ("std::thread::thread::Thread", "std::thread::Thread"),
+]
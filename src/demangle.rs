@@ -0,0 +1,718 @@
+use std::fmt::Write;
+use crate::parse::TypeName;
+use crate::style::TyNameStyle;
+
+/// Demangles a Rust symbol and [prettifies](crate::pretty) it, so that backtraces and profiles
+/// name types the same way as [`type_name`](crate::type_name).
+///
+/// Both the legacy (`_ZN…E`) and the v0 (`_R…`) schemes are understood, with or without the
+/// extra `_` that some platforms add. Legacy hashes and LLVM suffixes like `.llvm.123` are
+/// dropped. Returns `None` if `symbol` isn't a valid Rust symbol.
+///
+/// Generic arguments are written without a turbofish, as in `type_name`, and the paths of types
+/// are shortened even when they are followed by a method, eg `Vec<u8>::push`.
+/// ```
+/// # use ezty::demangle;
+/// assert_eq!(
+///     demangle("_ZN65_$LT$alloc..vec..Vec$LT$T$C$A$GT$$u20$as$u20$core..fmt..Debug$GT$3fmt17h46e653f69703202eE"),
+///     Some("<Vec<T, A> as Debug>::fmt".to_owned()),
+/// );
+/// assert_eq!(
+///     demangle("_RNvXsq_NtCslNYArtu3iFV_5alloc3vecINtB5_3VecINtNtCsgEmfK2I1SDS_4core6option6OptionhEENtNtBK_3fmt5Debug3fmt"),
+///     Some("<Vec<Option<u8>> as Debug>::fmt".to_owned()),
+/// );
+/// assert_eq!(demangle("main"), None);
+/// ```
+pub fn demangle(symbol: &str) -> Option<String> {
+    let demangled = demangle_raw(symbol)?;
+    let Ok(mut name) = TypeName::parse(&demangled) else {
+        return Some(demangled);
+    };
+    TyNameStyle::Pretty.apply_to_item(&mut name);
+    Some(TyNameStyle::Pretty.format(&name))
+}
+
+/// Demangles a symbol without prettifying it.
+pub(crate) fn demangle_raw(symbol: &str) -> Option<String> {
+    let symbol = symbol.strip_prefix('_').filter(|s| s.starts_with("_R") || s.starts_with("_ZN")).unwrap_or(symbol);
+    if let Some(v0) = symbol.strip_prefix("_R") {
+        // Anything after a `.` is a suffix added by LLVM.
+        let v0 = v0.split('.').next()?;
+        return V0 { src: v0.as_bytes(), pos: 0, depth: 0, budget: MAX_BACKREF_OUTPUT }.symbol();
+    }
+    legacy(symbol.strip_prefix("_ZN").or_else(|| symbol.strip_prefix("ZN"))?)
+}
+
+/// Demangles the part of a legacy symbol after the `_ZN`.
+fn legacy(mut src: &str) -> Option<String> {
+    let mut segments = vec![];
+    loop {
+        if let Some(rest) = src.strip_prefix('E') {
+            if !rest.is_empty() && !rest.starts_with('.') {
+                return None;
+            }
+            break;
+        }
+        let digits = src.bytes().take_while(u8::is_ascii_digit).count();
+        let len: usize = src[..digits].parse().ok()?;
+        let end = digits.checked_add(len)?;
+        let ident = src.get(digits..end)?;
+        src = &src[end..];
+        segments.push(ident);
+    }
+    // The last segment is usually a hash, like `h46e653f69703202e`.
+    if let Some(hash) = segments.last() {
+        if segments.len() > 1 && hash.len() == 17 && hash.starts_with('h') && hash[1..].bytes().all(|b| b.is_ascii_hexdigit()) {
+            segments.pop();
+        }
+    }
+    if segments.is_empty() {
+        return None;
+    }
+    let mut out = String::new();
+    for (i, segment) in segments.into_iter().enumerate() {
+        if i != 0 {
+            out.push_str("::");
+        }
+        legacy_ident(segment, &mut out)?;
+    }
+    Some(out)
+}
+
+/// Undoes the `$`-escapes of a legacy identifier, eg `_$LT$T$u20$as$u20$Trait$GT$`.
+fn legacy_ident(ident: &str, out: &mut String) -> Option<()> {
+    let mut rest = ident.strip_prefix("_$").map_or(ident, |_| &ident[1..]);
+    while let Some(c) = rest.chars().next() {
+        if let Some(escaped) = rest.strip_prefix('$') {
+            let end = escaped.find('$')?;
+            let decoded = match &escaped[..end] {
+                "SP" => '@',
+                "BP" => '*',
+                "RF" => '&',
+                "LT" => '<',
+                "GT" => '>',
+                "LP" => '(',
+                "RP" => ')',
+                "C" => ',',
+                code => char::from_u32(u32::from_str_radix(code.strip_prefix('u')?, 16).ok()?)?,
+            };
+            out.push(decoded);
+            rest = &escaped[end + 1..];
+        } else if let Some(after) = rest.strip_prefix("..") {
+            out.push_str("::");
+            rest = after;
+        } else {
+            out.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    Some(())
+}
+
+/// Paths & types nested deeper than this are rejected, rather than overflowing the stack.
+const MAX_DEPTH: usize = 100;
+
+/// How many bytes backrefs may write in all. Backrefs to backrefs can make the output grow
+/// exponentially with the length of the symbol, so symbols that need more are rejected.
+const MAX_BACKREF_OUTPUT: usize = 1 << 20;
+
+/// A v0 symbol, after the `_R`. See <https://doc.rust-lang.org/rustc/symbol-mangling/v0.html>.
+struct V0<'a> {
+    src: &'a [u8],
+    pos: usize,
+    /// How many paths, types & backrefs are being parsed, to stop runaway recursion.
+    depth: usize,
+    /// How many more bytes backrefs may write, from [`MAX_BACKREF_OUTPUT`].
+    budget: usize,
+}
+
+impl V0<'_> {
+    fn symbol(mut self) -> Option<String> {
+        // An optional encoding version.
+        while self.peek()?.is_ascii_digit() {
+            self.pos += 1;
+        }
+        let mut out = String::new();
+        self.path(&mut out)?;
+        // Whatever follows is the instantiating crate, which isn't written.
+        Some(out)
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.src.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<u8> {
+        let b = self.peek()?;
+        self.pos += 1;
+        Some(b)
+    }
+
+    fn eat(&mut self, b: u8) -> bool {
+        let ate = self.peek() == Some(b);
+        self.pos += ate as usize;
+        ate
+    }
+
+    /// A number like `1a_`, where `_` alone is 0.
+    fn base62(&mut self) -> Option<u64> {
+        if self.eat(b'_') {
+            return Some(0);
+        }
+        let mut n: u64 = 0;
+        loop {
+            let digit = match self.next()? {
+                b @ b'0'..=b'9' => b - b'0',
+                b @ b'a'..=b'z' => 10 + b - b'a',
+                b @ b'A'..=b'Z' => 36 + b - b'A',
+                b'_' => return n.checked_add(1),
+                _ => return None,
+            };
+            n = n.checked_mul(62)?.checked_add(digit as u64)?;
+        }
+    }
+
+    /// A decimal number, which has no leading zeros, so `00` is two numbers.
+    fn decimal(&mut self) -> Option<usize> {
+        if self.eat(b'0') {
+            return Some(0);
+        }
+        let start = self.pos;
+        while self.peek()?.is_ascii_digit() {
+            self.pos += 1;
+        }
+        std::str::from_utf8(&self.src[start..self.pos]).ok()?.parse().ok()
+    }
+
+    /// An `s`-prefixed disambiguator, which is 0 if absent.
+    fn disambiguator(&mut self) -> Option<u64> {
+        if self.eat(b's') {
+            self.base62()?.checked_add(1)
+        } else {
+            Some(0)
+        }
+    }
+
+    fn ident(&mut self) -> Option<String> {
+        let punycode = self.eat(b'u');
+        let len = self.decimal()?;
+        self.eat(b'_');
+        let end = self.pos.checked_add(len)?;
+        let bytes = self.src.get(self.pos..end)?;
+        self.pos = end;
+        let ident = std::str::from_utf8(bytes).ok()?;
+        if punycode {
+            punycode_decode(ident)
+        } else {
+            Some(ident.to_owned())
+        }
+    }
+
+    /// Parses whatever `f` does at the position given by a backref, writing to `out`.
+    ///
+    /// What the backref writes is taken from the budget, including what any backrefs inside it
+    /// write, so the budget runs out before the output can get huge.
+    fn backref(&mut self, out: &mut String, f: impl FnOnce(&mut Self, &mut String) -> Option<()>) -> Option<()> {
+        let start = self.pos - 1;
+        let target = usize::try_from(self.base62()?).ok()?;
+        if target >= start {
+            return None;
+        }
+        let pos = std::mem::replace(&mut self.pos, target);
+        let len = out.len();
+        self.nested(|v0| f(v0, out))?;
+        self.budget = self.budget.checked_sub((out.len() - len).max(1))?;
+        self.pos = pos;
+        Some(())
+    }
+
+    /// Calls `f` one level deeper, unless that is too deep.
+    fn nested<T>(&mut self, f: impl FnOnce(&mut Self) -> Option<T>) -> Option<T> {
+        if self.depth == MAX_DEPTH {
+            return None;
+        }
+        self.depth += 1;
+        let out = f(self);
+        self.depth -= 1;
+        out
+    }
+
+    fn path(&mut self, out: &mut String) -> Option<()> {
+        self.nested(|v0| v0.nested_path(out))
+    }
+
+    fn nested_path(&mut self, out: &mut String) -> Option<()> {
+        match self.next()? {
+            b'C' => {
+                self.disambiguator()?;
+                out.push_str(&self.ident()?);
+            }
+            b'M' => {
+                self.impl_path()?;
+                out.push('<');
+                self.ty(out)?;
+                out.push('>');
+            }
+            b'X' => {
+                self.impl_path()?;
+                out.push('<');
+                self.ty(out)?;
+                out.push_str(" as ");
+                self.path(out)?;
+                out.push('>');
+            }
+            b'Y' => {
+                out.push('<');
+                self.ty(out)?;
+                out.push_str(" as ");
+                self.path(out)?;
+                out.push('>');
+            }
+            b'N' => {
+                let namespace = self.next()?;
+                self.path(out)?;
+                let disambiguator = self.disambiguator()?;
+                let name = self.ident()?;
+                out.push_str("::");
+                match namespace {
+                    b'a'..=b'z' => out.push_str(&name),
+                    b'C' => write!(out, "{{closure#{disambiguator}}}").ok()?,
+                    b'S' if name.is_empty() => write!(out, "{{shim#{disambiguator}}}").ok()?,
+                    b'S' => write!(out, "{{shim:{name}#{disambiguator}}}").ok()?,
+                    other => {
+                        let other = other as char;
+                        if name.is_empty() {
+                            write!(out, "{{{other}#{disambiguator}}}").ok()?;
+                        } else {
+                            write!(out, "{{{other}:{name}#{disambiguator}}}").ok()?;
+                        }
+                    }
+                }
+            }
+            b'I' => {
+                let mut path = String::new();
+                self.path(&mut path)?;
+                // Like `type_name`, put the generics of a closure on the function it is in.
+                let mut at = path.len();
+                while path[..at].ends_with('}') {
+                    match path[..at].rfind("::{") {
+                        Some(i) => at = i,
+                        None => break,
+                    }
+                }
+                out.push_str(&path[..at]);
+                out.push('<');
+                let mut first = true;
+                while !self.eat(b'E') {
+                    if !std::mem::take(&mut first) {
+                        out.push_str(", ");
+                    }
+                    self.generic_arg(out)?;
+                }
+                out.push('>');
+                out.push_str(&path[at..]);
+            }
+            b'B' => return self.backref(out, Self::path),
+            _ => return None,
+        }
+        Some(())
+    }
+
+    /// The path of an impl, which isn't written.
+    fn impl_path(&mut self) -> Option<()> {
+        self.disambiguator()?;
+        self.path(&mut String::new())
+    }
+
+    fn generic_arg(&mut self, out: &mut String) -> Option<()> {
+        if self.eat(b'L') {
+            self.base62()?;
+            out.push_str("'_");
+            Some(())
+        } else if self.eat(b'K') {
+            self.konst(out)
+        } else {
+            self.ty(out)
+        }
+    }
+
+    fn ty(&mut self, out: &mut String) -> Option<()> {
+        self.nested(|v0| v0.nested_ty(out))
+    }
+
+    fn nested_ty(&mut self, out: &mut String) -> Option<()> {
+        let basic = match self.peek()? {
+            b'a' => "i8",
+            b'b' => "bool",
+            b'c' => "char",
+            b'd' => "f64",
+            b'e' => "str",
+            b'f' => "f32",
+            b'h' => "u8",
+            b'i' => "isize",
+            b'j' => "usize",
+            b'l' => "i32",
+            b'm' => "u32",
+            b'n' => "i128",
+            b'o' => "u128",
+            b's' => "i16",
+            b't' => "u16",
+            b'u' => "()",
+            b'v' => "...",
+            b'x' => "i64",
+            b'y' => "u64",
+            b'z' => "!",
+            b'p' => "_",
+            _ => "",
+        };
+        if !basic.is_empty() {
+            self.pos += 1;
+            out.push_str(basic);
+            return Some(());
+        }
+        match self.next()? {
+            b'A' => {
+                out.push('[');
+                self.ty(out)?;
+                out.push_str("; ");
+                self.konst(out)?;
+                out.push(']');
+            }
+            b'S' => {
+                out.push('[');
+                self.ty(out)?;
+                out.push(']');
+            }
+            b'T' => {
+                out.push('(');
+                let mut n = 0;
+                while !self.eat(b'E') {
+                    if n != 0 {
+                        out.push_str(", ");
+                    }
+                    self.ty(out)?;
+                    n += 1;
+                }
+                if n == 1 {
+                    out.push(',');
+                }
+                out.push(')');
+            }
+            b @ (b'R' | b'Q') => {
+                out.push('&');
+                if self.eat(b'L') {
+                    self.base62()?;
+                }
+                if b == b'Q' {
+                    out.push_str("mut ");
+                }
+                self.ty(out)?;
+            }
+            b'P' => {
+                out.push_str("*const ");
+                self.ty(out)?;
+            }
+            b'O' => {
+                out.push_str("*mut ");
+                self.ty(out)?;
+            }
+            b'F' => self.fn_sig(out)?,
+            b'D' => {
+                if self.eat(b'G') {
+                    self.base62()?;
+                }
+                out.push_str("dyn ");
+                let mut first = true;
+                while !self.eat(b'E') {
+                    if !std::mem::take(&mut first) {
+                        out.push_str(" + ");
+                    }
+                    self.dyn_trait(out)?;
+                }
+                // The object's lifetime.
+                if !self.eat(b'L') {
+                    return None;
+                }
+                self.base62()?;
+            }
+            b'B' => return self.backref(out, Self::ty),
+            _ => {
+                self.pos -= 1;
+                self.path(out)?;
+            }
+        }
+        Some(())
+    }
+
+    fn fn_sig(&mut self, out: &mut String) -> Option<()> {
+        if self.eat(b'G') {
+            self.base62()?;
+        }
+        if self.eat(b'U') {
+            out.push_str("unsafe ");
+        }
+        if self.eat(b'K') {
+            if self.eat(b'C') {
+                out.push_str("extern \"C\" ");
+            } else {
+                write!(out, "extern \"{}\" ", self.ident()?.replace('_', "-")).ok()?;
+            }
+        }
+        out.push_str("fn(");
+        let mut first = true;
+        while !self.eat(b'E') {
+            if !std::mem::take(&mut first) {
+                out.push_str(", ");
+            }
+            self.ty(out)?;
+        }
+        out.push(')');
+        let mut ret = String::new();
+        self.ty(&mut ret)?;
+        if ret != "()" {
+            out.push_str(" -> ");
+            out.push_str(&ret);
+        }
+        Some(())
+    }
+
+    fn dyn_trait(&mut self, out: &mut String) -> Option<()> {
+        let mut path = String::new();
+        self.path(&mut path)?;
+        let mut bindings = vec![];
+        while self.eat(b'p') {
+            let name = self.ident()?;
+            let mut ty = String::new();
+            self.ty(&mut ty)?;
+            bindings.push(format!("{name} = {ty}"));
+        }
+        if bindings.is_empty() {
+            out.push_str(&path);
+        } else if let Some(open) = path.strip_suffix('>') {
+            write!(out, "{open}, {}>", bindings.join(", ")).ok()?;
+        } else {
+            write!(out, "{path}<{}>", bindings.join(", ")).ok()?;
+        }
+        Some(())
+    }
+
+    fn konst(&mut self, out: &mut String) -> Option<()> {
+        if self.eat(b'p') {
+            out.push('_');
+            return Some(());
+        }
+        if self.eat(b'B') {
+            return self.backref(out, Self::konst);
+        }
+        let ty = self.next()?;
+        let negative = self.eat(b'n');
+        let start = self.pos;
+        while self.peek()?.is_ascii_hexdigit() {
+            self.pos += 1;
+        }
+        let hex = std::str::from_utf8(&self.src[start..self.pos]).ok()?;
+        if !self.eat(b'_') {
+            return None;
+        }
+        let value = if hex.is_empty() { 0 } else { u128::from_str_radix(hex, 16).ok()? };
+        match ty {
+            b'b' => out.push_str(if value == 0 { "false" } else { "true" }),
+            b'c' => write!(out, "{:?}", char::from_u32(u32::try_from(value).ok()?)?).ok()?,
+            b'a' | b'h' | b'i' | b'j' | b'l' | b'm' | b'n' | b'o' | b's' | b't' | b'x' | b'y' => {
+                write!(out, "{}{value}", if negative { "-" } else { "" }).ok()?
+            }
+            _ => return None,
+        }
+        Some(())
+    }
+}
+
+/// Decodes a punycode identifier, as in RFC 3492, with `_` as the delimiter.
+fn punycode_decode(ident: &str) -> Option<String> {
+    let (basic, encoded) = match ident.rfind('_') {
+        Some(i) => (&ident[..i], &ident[i + 1..]),
+        None => ("", ident),
+    };
+    let mut out: Vec<char> = basic.chars().collect();
+    let (base, t_min, t_max, skew, damp) = (36u32, 1u32, 26u32, 38u32, 700u32);
+    let (mut n, mut bias, mut i) = (128u32, 72u32, 0u32);
+    let mut digits = encoded.bytes().peekable();
+    while digits.peek().is_some() {
+        let old_i = i;
+        let mut w = 1u32;
+        let mut k = base;
+        loop {
+            let digit = match digits.next()? {
+                b @ b'a'..=b'z' => b - b'a',
+                b @ b'0'..=b'9' => b - b'0' + 26,
+                _ => return None,
+            } as u32;
+            i = i.checked_add(digit.checked_mul(w)?)?;
+            let t = k.saturating_sub(bias).clamp(t_min, t_max);
+            if digit < t {
+                break;
+            }
+            w = w.checked_mul(base - t)?;
+            k += base;
+        }
+        let len = out.len() as u32 + 1;
+        let mut delta = if old_i == 0 { (i - old_i) / damp } else { (i - old_i) / 2 };
+        delta += delta / len;
+        let mut k = 0;
+        while delta > ((base - t_min) * t_max) / 2 {
+            delta /= base - t_min;
+            k += base;
+        }
+        bias = k + (base - t_min + 1) * delta / (delta + skew);
+        n = n.checked_add(i / len)?;
+        i %= len;
+        out.insert(i as usize, char::from_u32(n)?);
+        i += 1;
+    }
+    Some(out.into_iter().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{demangle, demangle_raw, punycode_decode};
+
+    #[test]
+    fn legacy() {
+        assert_eq!(demangle_raw("_ZN1s4main17h35151d0fd6a5d255E").as_deref(), Some("s::main"));
+        assert_eq!(demangle_raw("__ZN1s4main17h35151d0fd6a5d255E").as_deref(), Some("s::main"));
+        assert_eq!(demangle_raw("_ZN1s4main17h35151d0fd6a5d255E.llvm.1234").as_deref(), Some("s::main"));
+        assert_eq!(
+            demangle_raw("_ZN42_$LT$$RF$T$u20$as$u20$core..fmt..Debug$GT$3fmt17hdd7033e4037c8388E").as_deref(),
+            Some("<&T as core::fmt::Debug>::fmt"),
+        );
+        assert_eq!(
+            demangle_raw("_ZN4core3ptr85drop_in_place$LT$std..rt..lang_start$LT$$LP$$RP$$GT$..$u7b$$u7b$closure$u7d$$u7d$$GT$17h0123456789abcdefE").as_deref(),
+            Some("core::ptr::drop_in_place<std::rt::lang_start<()>::{{closure}}>"),
+        );
+        assert_eq!(demangle_raw("_ZN1s4mainE_"), None);
+        assert_eq!(demangle_raw("_ZN99sE"), None);
+    }
+
+    #[test]
+    fn v0() {
+        // Compared with the output of GNU nm, without the turbofishes.
+        let cases = [
+            ("_RNvCsd2pkCS1BQMS_1s4main", "s::main"),
+            ("_RNCINvNtCsjrHSEGnQ3l9_3std2rt10lang_startuE0Csd2pkCS1BQMS_1s", "std::rt::lang_start<()>::{closure#0}"),
+            (
+                "_RNvXCsd2pkCS1BQMS_1sINtB2_1WINtNtCslNYArtu3iFV_5alloc3vec3VecINtNtCsgEmfK2I1SDS_4core6option6OptionhEEENtNtB12_3fmt7Display3fmtB2_",
+                "<s::W<alloc::vec::Vec<core::option::Option<u8>>> as core::fmt::Display>::fmt",
+            ),
+            ("_RNvXs1g_NtCsgEmfK2I1SDS_4core3fmtRhNtB6_5Debug3fmtCsd2pkCS1BQMS_1s", "<&u8 as core::fmt::Debug>::fmt"),
+            (
+                "_RNvYNCNvCsd2pkCS1BQMS_1s4mains0_0INtNtNtCsgEmfK2I1SDS_4core3ops8function6FnOnceTcEE9call_onceB6_",
+                "<s::main::{closure#2} as core::ops::function::FnOnce<(char,)>>::call_once",
+            ),
+            (
+                "_RINvNtNtCsjrHSEGnQ3l9_3std3sys9backtrace28___rust_begin_short_backtraceFEuuECsd2pkCS1BQMS_1s",
+                "std::sys::backtrace::__rust_begin_short_backtrace<fn(), ()>",
+            ),
+            (
+                "_RNSNvYNCINvNtCsjrHSEGnQ3l9_3std2rt10lang_startuE0INtNtNtCsgEmfK2I1SDS_4core3ops8function6FnOnceuE9call_once6vtableCsd2pkCS1BQMS_1s",
+                "<std::rt::lang_start<()>::{closure#0} as core::ops::function::FnOnce<()>>::call_once::{shim:vtable#0}",
+            ),
+            ("_RINvMs5_NtNtCsjrHSEGnQ3l9_3std2io5errorNtB6_5Error3newReEBa_", "<std::io::error::Error>::new<&str>"),
+            (
+                "_RMCs4fqI2P2rA04_13const_genericINtB0_4FlagKh7b_Kb1_Kc7f_Kan2a_E",
+                "<const_generic::Flag<123, true, '\\u{7f}', -42>>",
+            ),
+            ("_RNvNvC4test4func1u", "test::func::u"),
+            ("_RINvC1a1fFKCEuFUKCRhEuDNvC1a1Tp1XhEL_E", "a::f<extern \"C\" fn(), unsafe extern \"C\" fn(&u8), dyn a::T<X = u8>>"),
+        ];
+        for (symbol, expected) in cases {
+            assert_eq!(demangle_raw(symbol).as_deref(), Some(expected), "{symbol}");
+        }
+        assert_eq!(
+            demangle_raw("_RNCNCNvNtNtCsjrHSEGnQ3l9_3std3sys9backtrace10__print_fmts_00B9_").as_deref(),
+            Some("std::sys::backtrace::_print_fmt::{closure#1}::{closure#0}"),
+        );
+        assert_eq!(demangle_raw("_RNvC"), None);
+        assert_eq!(demangle_raw("_RB0_"), None);
+    }
+
+    #[test]
+    fn huge_lengths() {
+        let symbols = [
+            "_ZN18446744073709551615abcE",
+            "_ZN18446744073709551616abcE",
+            "_ZN1a18446744073709551614abE",
+            "_RNvC18446744073709551615_ab",
+            "_RNvC18446744073709551616_ab",
+            "_RNvCs_1a18446744073709551615ab",
+            "_RNvCu18446744073709551615_ab",
+        ];
+        for symbol in symbols {
+            assert_eq!(demangle(symbol), None, "{symbol}");
+        }
+    }
+
+    #[test]
+    fn deeply_nested() {
+        let symbol = |n| format!("_RINvC1a1f{}hE", "R".repeat(n));
+        assert_eq!(demangle_raw(&symbol(97)), Some(format!("a::f<{}u8>", "&".repeat(97))));
+        assert_eq!(demangle_raw(&symbol(100_000)), None);
+        assert_eq!(demangle_raw(&format!("_RNvC1a{}1f", "N".repeat(100_000))), None);
+    }
+
+    #[test]
+    fn exponential_backrefs() {
+        // Each level is a pair of the level before, so the output doubles with every level.
+        let symbol = |levels| {
+            let mut symbol = "_RINvC1a1fh".to_owned();
+            let mut prev = 8;
+            for _ in 0..levels {
+                let backref = if prev == 0 { "_".to_owned() } else { base62(prev - 1) + "_" };
+                let start = symbol.len() - 2;
+                symbol += &format!("TB{backref}B{backref}E");
+                prev = start;
+            }
+            symbol + "E"
+        };
+        fn base62(mut n: usize) -> String {
+            let digits = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+            let mut out = vec![];
+            loop {
+                out.insert(0, digits[n % 62]);
+                n /= 62;
+                if n == 0 {
+                    break String::from_utf8(out).unwrap();
+                }
+            }
+        }
+        assert_eq!(demangle_raw(&symbol(2)).as_deref(), Some("a::f<u8, (u8, u8), ((u8, u8), (u8, u8))>"));
+        assert!(demangle_raw(&symbol(12)).is_some());
+        let huge = symbol(30);
+        assert!(huge.len() < 300);
+        assert_eq!(demangle_raw(&huge), None);
+        assert_eq!(demangle(&huge), None);
+    }
+
+    #[test]
+    fn punycode() {
+        assert_eq!(punycode_decode("gdel_5qa").as_deref(), Some("gödel"));
+        assert_eq!(punycode_decode("fiqs8s").as_deref(), Some("中国"));
+    }
+
+    #[test]
+    fn prettified() {
+        assert_eq!(
+            demangle("_RINvMs5_NtNtCsjrHSEGnQ3l9_3std2io5errorNtB6_5Error3newReEBa_").as_deref(),
+            Some("<io::Error>::new<&str>"),
+        );
+        assert_eq!(
+            demangle("_ZN5alloc3vec16Vec$LT$T$C$A$GT$4push17h0123456789abcdefE").as_deref(),
+            Some("Vec<T, A>::push"),
+        );
+        assert_eq!(
+            demangle("_ZN4core3ptr85drop_in_place$LT$std..rt..lang_start$LT$$LP$$RP$$GT$..$u7b$$u7b$closure$u7d$$u7d$$GT$17h0123456789abcdefE").as_deref(),
+            Some("core::ptr::drop_in_place<closure in lang_start<()>>"),
+        );
+        assert_eq!(demangle("_ZN1s4main17h35151d0fd6a5d255E").as_deref(), Some("s::main"));
+        assert_eq!(demangle("_RNvCsd2pkCS1BQMS_1s4main").as_deref(), Some("s::main"));
+    }
+}
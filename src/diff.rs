@@ -0,0 +1,244 @@
+use std::fmt;
+use std::ops::Range;
+use crate::parse::{GenericArgs, PathSegment, TypeName, TypePath};
+use crate::style::TyNameStyle;
+use crate::Ty;
+
+/// Where the names of two types differ. Returned by [`Ty::diff`].
+///
+/// The trees of the two [`pretty`](crate::pretty) names are walked together, and only the
+/// smallest parts that differ are reported, eg just `u32` & `u64` for
+/// `HashMap<String, Vec<u32>>` & `HashMap<String, Vec<u64>>`. If the pretty names are the same
+/// the full names are compared instead.
+///
+/// Displaying a `TyDiff` shows both names, with the differences underlined by `^`.
+/// ```
+/// # use ezty::Ty;
+/// let diff = Ty::of::<Option<Vec<u32>>>().diff(&Ty::of::<Option<Vec<u64>>>());
+/// assert_eq!(diff.differences().collect::<Vec<_>>(), [("u32", "u64")]);
+/// assert_eq!(diff.to_string().lines().collect::<Vec<_>>(), [
+///     " left: Option<Vec<u32>>",
+///     "                  ^^^",
+///     "right: Option<Vec<u64>>",
+///     "                  ^^^",
+/// ]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TyDiff {
+    left: String,
+    right: String,
+    /// Byte ranges of the differences in `left` & `right`.
+    spans: Vec<(Range<usize>, Range<usize>)>,
+}
+
+impl TyDiff {
+    pub(crate) fn new(left: &Ty, right: &Ty) -> TyDiff {
+        let diff = TyDiff::of_names(left.full_name(), right.full_name(), TyNameStyle::Pretty);
+        if diff.left == diff.right && left != right {
+            TyDiff::of_names(left.full_name(), right.full_name(), TyNameStyle::Full)
+        } else {
+            diff
+        }
+    }
+
+    /// Compares two [`type_name()`](std::any::type_name)s, rendered in `style`.
+    fn of_names(left: &str, right: &str, style: TyNameStyle) -> TyDiff {
+        let (Ok(mut l), Ok(mut r)) = (TypeName::parse(left), TypeName::parse(right)) else {
+            let (left, right) = (style.render(left).into_owned(), style.render(right).into_owned());
+            let spans = if left == right { vec![] } else { vec![(0..left.len(), 0..right.len())] };
+            return TyDiff { left, right, spans };
+        };
+        style.apply(&mut l);
+        style.apply(&mut r);
+        let mut parts = vec![];
+        align(&mut l, &mut r, &mut parts);
+        let (left_parts, right_parts): (Vec<_>, Vec<_>) = parts.into_iter().unzip();
+        let (left, left_spans) = expand(&format!("{l:#}"), &left_parts);
+        let (right, right_spans) = expand(&format!("{r:#}"), &right_parts);
+        TyDiff { left, right, spans: left_spans.into_iter().zip(right_spans).collect() }
+    }
+
+    /// The left name, as it is displayed.
+    pub fn left(&self) -> &str {
+        &self.left
+    }
+
+    /// The right name, as it is displayed.
+    pub fn right(&self) -> &str {
+        &self.right
+    }
+
+    /// Are the names the same? Distinct types can still have the same name.
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// The parts of the left & right names that differ, outermost first.
+    pub fn differences(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.spans.iter().map(|(l, r)| (&self.left[l.clone()], &self.right[r.clone()]))
+    }
+
+    /// Like the `Display` impl, but the differences are coloured with ANSI escape codes rather
+    /// than underlined.
+    pub fn to_ansi_string(&self) -> String {
+        let paint = |name: &str, spans: &mut dyn Iterator<Item = &Range<usize>>, colour: &str| {
+            let mut out = String::new();
+            let mut pos = 0;
+            for span in spans {
+                out.push_str(&name[pos..span.start]);
+                out.push_str(&format!("\x1b[1;{colour}m{}\x1b[0m", &name[span.clone()]));
+                pos = span.end;
+            }
+            out.push_str(&name[pos..]);
+            out
+        };
+        format!(
+            " left: {}\nright: {}",
+            paint(&self.left, &mut self.spans.iter().map(|(l, _)| l), "31"),
+            paint(&self.right, &mut self.spans.iter().map(|(_, r)| r), "32"),
+        )
+    }
+}
+
+impl fmt::Display for TyDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let underline = |name: &str, spans: &mut dyn Iterator<Item = &Range<usize>>| {
+            let mut line = String::new();
+            for span in spans {
+                let start = name[..span.start].chars().count();
+                let len = name[span.clone()].chars().count().max(1);
+                line.extend(std::iter::repeat_n(' ', start - line.chars().count()));
+                line.extend(std::iter::repeat_n('^', len));
+            }
+            line
+        };
+        write!(f, " left: {}", self.left)?;
+        if !self.is_empty() {
+            write!(f, "\n       {}", underline(&self.left, &mut self.spans.iter().map(|(l, _)| l)))?;
+        }
+        write!(f, "\nright: {}", self.right)?;
+        if !self.is_empty() {
+            write!(f, "\n       {}", underline(&self.right, &mut self.spans.iter().map(|(_, r)| r)))?;
+        }
+        Ok(())
+    }
+}
+
+/// Walks the two trees together, replacing the parts that differ with placeholders and adding
+/// them to `parts`.
+fn align(left: &mut TypeName, right: &mut TypeName, parts: &mut Vec<(String, String)>) {
+    if left == right {
+        return;
+    }
+    if left.same_shape(right) {
+        for (left, right) in left.children_mut().into_iter().zip(right.children_mut()) {
+            align(left, right, parts);
+        }
+        return;
+    }
+    parts.push((format!("{left:#}"), format!("{right:#}")));
+    let placeholder = TypeName::Path(TypePath {
+        segments: vec![PathSegment { name: format!("\0{}\0", parts.len() - 1), args: GenericArgs::None }],
+    });
+    *left = placeholder.clone();
+    *right = placeholder;
+}
+
+/// Puts `parts` back in place of the placeholders, and returns where they went.
+fn expand(rendered: &str, parts: &[String]) -> (String, Vec<Range<usize>>) {
+    let mut out = String::with_capacity(rendered.len());
+    let mut spans = vec![None; parts.len()];
+    let mut pieces = rendered.split('\0');
+    out.push_str(pieces.next().unwrap_or_default());
+    while let (Some(index), Some(rest)) = (pieces.next(), pieces.next()) {
+        let part = index.parse().ok().and_then(|i: usize| Some((i, parts.get(i)?)));
+        if let Some((i, part)) = part {
+            spans[i] = Some(out.len()..out.len() + part.len());
+            out.push_str(part);
+        }
+        out.push_str(rest);
+    }
+    (out, spans.into_iter().flatten().collect())
+}
+
+/// Asserts that two [`Ty`]s are equal, showing where their names differ if they aren't.
+/// ```should_panic
+/// # use ezty::{assert_ty_eq, Ty};
+/// assert_ty_eq!(Ty::of::<Vec<u32>>(), Ty::of::<Vec<u64>>());
+/// ```
+#[macro_export]
+macro_rules! assert_ty_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if *left != *right {
+                    panic!("assertion `left == right` failed\n{}", $crate::Ty::diff(left, right));
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if *left != *right {
+                    panic!(
+                        "assertion `left == right` failed: {}\n{}",
+                        format_args!($($arg)+),
+                        $crate::Ty::diff(left, right),
+                    );
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Ty;
+    use std::collections::HashMap;
+
+    #[test]
+    fn differences() {
+        let diff = Ty::of::<HashMap<String, Vec<Option<u32>>>>().diff(&Ty::of::<HashMap<String, Vec<Option<u64>>>>());
+        assert_eq!(diff.left(), "HashMap<String, Vec<Option<u32>>>");
+        assert_eq!(diff.differences().collect::<Vec<_>>(), [("u32", "u64")]);
+        let diff = Ty::of::<(u8, Vec<u8>, String)>().diff(&Ty::of::<(u16, Box<u8>, String)>());
+        assert_eq!(diff.differences().collect::<Vec<_>>(), [("u8", "u16"), ("Vec<u8>", "Box<u8>")]);
+        assert_eq!(diff.to_string(), " left: (u8, Vec<u8>, String)\n        ^^  ^^^^^^^\nright: (u16, Box<u8>, String)\n        ^^^  ^^^^^^^");
+        let diff = Ty::of::<&u8>().diff(&Ty::of::<&mut u8>());
+        assert_eq!(diff.differences().collect::<Vec<_>>(), [("&u8", "&mut u8")]);
+        let same = Ty::of::<Vec<u8>>().diff(&Ty::of::<Vec<u8>>());
+        assert!(same.is_empty());
+        assert_eq!(same.to_string(), " left: Vec<u8>\nright: Vec<u8>");
+    }
+
+    #[test]
+    fn same_pretty_names() {
+        mod a {
+            pub struct Row;
+        }
+        mod b {
+            pub struct Row;
+        }
+        let _rules = crate::PrettyRules::builtin().strip_matching("**::Row").override_scope();
+        let diff = Ty::of::<Vec<a::Row>>().diff(&Ty::of::<Vec<b::Row>>());
+        assert_eq!(
+            diff.differences().collect::<Vec<_>>(),
+            [("ezty::diff::tests::same_pretty_names::a::Row", "ezty::diff::tests::same_pretty_names::b::Row")],
+        );
+    }
+
+    #[test]
+    fn ansi() {
+        let diff = Ty::of::<Option<u8>>().diff(&Ty::of::<Option<i8>>());
+        assert_eq!(diff.to_ansi_string(), " left: Option<\x1b[1;31mu8\x1b[0m>\nright: Option<\x1b[1;32mi8\x1b[0m>");
+    }
+
+    #[test]
+    fn assert_ty_eq() {
+        crate::assert_ty_eq!(Ty::of::<u8>(), Ty::of::<u8>());
+        let panic = std::panic::catch_unwind(|| crate::assert_ty_eq!(Ty::of::<Vec<u8>>(), Ty::of::<Vec<i8>>(), "{}", 1));
+        let msg = panic.unwrap_err().downcast::<String>().unwrap();
+        assert_eq!(*msg, "assertion `left == right` failed: 1\n left: Vec<u8>\n           ^^\nright: Vec<i8>\n           ^^");
+    }
+}
@@ -0,0 +1,160 @@
+&[
+    // The kind of every item in `pretty.expr.rs` that isn't a struct, by its public path, for
+    // links to its documentation. Derived from the std docs of Rust 1.95; each entry is (public
+    // path, kind).
+    ("std::alloc::GlobalAlloc", "trait"),
+    ("std::any::Any", "trait"),
+    ("std::ascii::AsciiExt", "trait"),
+    ("std::backtrace::BacktraceStatus", "enum"),
+    ("std::borrow::Borrow", "trait"),
+    ("std::borrow::BorrowMut", "trait"),
+    ("std::borrow::Cow", "enum"),
+    ("std::borrow::ToOwned", "trait"),
+    ("std::clone::Clone", "trait"),
+    ("std::cmp::Eq", "trait"),
+    ("std::cmp::Ord", "trait"),
+    ("std::cmp::Ordering", "enum"),
+    ("std::cmp::PartialEq", "trait"),
+    ("std::cmp::PartialOrd", "trait"),
+    ("std::collections::btree_map::Entry", "enum"),
+    ("std::collections::hash_map::Entry", "enum"),
+    ("std::convert::AsMut", "trait"),
+    ("std::convert::AsRef", "trait"),
+    ("std::convert::From", "trait"),
+    ("std::convert::Infallible", "enum"),
+    ("std::convert::Into", "trait"),
+    ("std::convert::TryFrom", "trait"),
+    ("std::convert::TryInto", "trait"),
+    ("std::default::Default", "trait"),
+    ("std::env::VarError", "enum"),
+    ("std::error::Error", "trait"),
+    ("std::ffi::FromBytesWithNulError", "enum"),
+    ("std::ffi::c_void", "enum"),
+    ("std::fmt::Alignment", "enum"),
+    ("std::fmt::Binary", "trait"),
+    ("std::fmt::Debug", "trait"),
+    ("std::fmt::Display", "trait"),
+    ("std::fmt::LowerExp", "trait"),
+    ("std::fmt::LowerHex", "trait"),
+    ("std::fmt::Octal", "trait"),
+    ("std::fmt::Pointer", "trait"),
+    ("std::fmt::UpperExp", "trait"),
+    ("std::fmt::UpperHex", "trait"),
+    ("std::fmt::Write", "trait"),
+    ("std::fs::TryLockError", "enum"),
+    ("std::future::Future", "trait"),
+    ("std::future::IntoFuture", "trait"),
+    ("std::hash::BuildHasher", "trait"),
+    ("std::hash::Hash", "trait"),
+    ("std::hash::Hasher", "trait"),
+    ("std::io::BufRead", "trait"),
+    ("std::io::ErrorKind", "enum"),
+    ("std::io::IsTerminal", "trait"),
+    ("std::io::Read", "trait"),
+    ("std::io::Seek", "trait"),
+    ("std::io::SeekFrom", "enum"),
+    ("std::io::Write", "trait"),
+    ("std::iter::DoubleEndedIterator", "trait"),
+    ("std::iter::ExactSizeIterator", "trait"),
+    ("std::iter::Extend", "trait"),
+    ("std::iter::FromIterator", "trait"),
+    ("std::iter::FusedIterator", "trait"),
+    ("std::iter::IntoIterator", "trait"),
+    ("std::iter::Iterator", "trait"),
+    ("std::iter::Product", "trait"),
+    ("std::iter::Sum", "trait"),
+    ("std::marker::Copy", "trait"),
+    ("std::marker::Send", "trait"),
+    ("std::marker::Sized", "trait"),
+    ("std::marker::Sync", "trait"),
+    ("std::marker::Unpin", "trait"),
+    ("std::mem::MaybeUninit", "union"),
+    ("std::net::IpAddr", "enum"),
+    ("std::net::Shutdown", "enum"),
+    ("std::net::SocketAddr", "enum"),
+    ("std::net::ToSocketAddrs", "trait"),
+    ("std::num::FpCategory", "enum"),
+    ("std::num::IntErrorKind", "enum"),
+    ("std::num::NonZeroI128", "type"),
+    ("std::num::NonZeroI16", "type"),
+    ("std::num::NonZeroI32", "type"),
+    ("std::num::NonZeroI64", "type"),
+    ("std::num::NonZeroI8", "type"),
+    ("std::num::NonZeroIsize", "type"),
+    ("std::num::NonZeroU128", "type"),
+    ("std::num::NonZeroU16", "type"),
+    ("std::num::NonZeroU32", "type"),
+    ("std::num::NonZeroU64", "type"),
+    ("std::num::NonZeroU8", "type"),
+    ("std::num::NonZeroUsize", "type"),
+    ("std::ops::Add", "trait"),
+    ("std::ops::AddAssign", "trait"),
+    ("std::ops::BitAnd", "trait"),
+    ("std::ops::BitAndAssign", "trait"),
+    ("std::ops::BitOr", "trait"),
+    ("std::ops::BitOrAssign", "trait"),
+    ("std::ops::BitXor", "trait"),
+    ("std::ops::BitXorAssign", "trait"),
+    ("std::ops::Bound", "enum"),
+    ("std::ops::ControlFlow", "enum"),
+    ("std::ops::Deref", "trait"),
+    ("std::ops::DerefMut", "trait"),
+    ("std::ops::Div", "trait"),
+    ("std::ops::DivAssign", "trait"),
+    ("std::ops::Drop", "trait"),
+    ("std::ops::Fn", "trait"),
+    ("std::ops::FnMut", "trait"),
+    ("std::ops::FnOnce", "trait"),
+    ("std::ops::Index", "trait"),
+    ("std::ops::IndexMut", "trait"),
+    ("std::ops::Mul", "trait"),
+    ("std::ops::MulAssign", "trait"),
+    ("std::ops::Neg", "trait"),
+    ("std::ops::Not", "trait"),
+    ("std::ops::RangeBounds", "trait"),
+    ("std::ops::Rem", "trait"),
+    ("std::ops::RemAssign", "trait"),
+    ("std::ops::Shl", "trait"),
+    ("std::ops::ShlAssign", "trait"),
+    ("std::ops::Shr", "trait"),
+    ("std::ops::ShrAssign", "trait"),
+    ("std::ops::Sub", "trait"),
+    ("std::ops::SubAssign", "trait"),
+    ("std::option::Option", "enum"),
+    ("std::os::fd::AsFd", "trait"),
+    ("std::os::fd::AsRawFd", "trait"),
+    ("std::os::fd::FromRawFd", "trait"),
+    ("std::os::fd::IntoRawFd", "trait"),
+    ("std::os::linux::fs::MetadataExt", "trait"),
+    ("std::os::linux::net::SocketAddrExt", "trait"),
+    ("std::os::linux::net::TcpStreamExt", "trait"),
+    ("std::os::unix::ffi::OsStrExt", "trait"),
+    ("std::os::unix::ffi::OsStringExt", "trait"),
+    ("std::os::unix::fs::DirBuilderExt", "trait"),
+    ("std::os::unix::fs::DirEntryExt", "trait"),
+    ("std::os::unix::fs::FileExt", "trait"),
+    ("std::os::unix::fs::FileTypeExt", "trait"),
+    ("std::os::unix::fs::MetadataExt", "trait"),
+    ("std::os::unix::fs::OpenOptionsExt", "trait"),
+    ("std::os::unix::fs::PermissionsExt", "trait"),
+    ("std::os::unix::process::CommandExt", "trait"),
+    ("std::os::unix::process::ExitStatusExt", "trait"),
+    ("std::os::unix::thread::JoinHandleExt", "trait"),
+    ("std::panic::RefUnwindSafe", "trait"),
+    ("std::panic::UnwindSafe", "trait"),
+    ("std::path::Component", "enum"),
+    ("std::path::Prefix", "enum"),
+    ("std::process::Termination", "trait"),
+    ("std::result::Result", "enum"),
+    ("std::slice::GetDisjointMutError", "enum"),
+    ("std::slice::SliceIndex", "trait"),
+    ("std::str::FromStr", "trait"),
+    ("std::string::ToString", "trait"),
+    ("std::sync::TryLockError", "enum"),
+    ("std::sync::atomic::Ordering", "enum"),
+    ("std::sync::mpsc::RecvTimeoutError", "enum"),
+    ("std::sync::mpsc::TryRecvError", "enum"),
+    ("std::sync::mpsc::TrySendError", "enum"),
+    ("std::task::Poll", "enum"),
+    ("std::task::Wake", "trait"),
+]
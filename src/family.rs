@@ -0,0 +1,130 @@
+use std::fmt;
+use crate::intern::intern;
+use crate::parse::{GenericArg, GenericArgs, TypeName, TypePath};
+use crate::style::TyNameStyle;
+use crate::Ty;
+
+/// A type with its generic arguments erased, eg `alloc::vec::Vec` with 1 argument for every
+/// `Vec<T>`. Returned by [`Ty::family`].
+///
+/// Types that aren't paths keep their shape, with the types inside them erased: every pair is
+/// in the `(_, _)` family, and every array in the `[_; _]` family, whatever its length.
+/// Lifetimes aren't counted.
+/// ```
+/// # use ezty::Ty;
+/// # use std::collections::HashMap;
+/// let family = Ty::of::<HashMap<String, u8>>().family();
+/// assert_eq!(family.path(), "std::collections::hash::map::HashMap");
+/// assert_eq!(family.arity(), 2);
+/// assert_eq!(family.to_string(), "HashMap<_, _>");
+/// assert!(Ty::of::<Vec<u8>>().same_family(&Ty::of::<Vec<String>>()));
+/// assert!(!Ty::of::<Vec<u8>>().same_family(&Ty::of::<Option<u8>>()));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TyFamily {
+    path: &'static str,
+    arity: usize,
+    /// Is the arity written as generic arguments?
+    args: bool,
+}
+
+impl TyFamily {
+    pub(crate) fn of(ty: &Ty) -> TyFamily {
+        let Ok(name) = ty.parse() else {
+            return TyFamily { path: ty.full_name(), arity: 0, args: false };
+        };
+        match &name {
+            TypeName::Path(path) => TyFamily::of_path(path, None),
+            TypeName::Closure { parent, marker } => TyFamily::of_path(parent, Some(marker)),
+            _ => {
+                let mut skeleton = name.clone();
+                let children = skeleton.children_mut();
+                let arity = children.len();
+                for child in children {
+                    *child = TypeName::Infer;
+                }
+                if let TypeName::Array { len, .. } = &mut skeleton {
+                    *len = "_".into();
+                }
+                TyFamily { path: intern(&skeleton.to_string()), arity, args: false }
+            }
+        }
+    }
+
+    fn of_path(path: &TypePath, marker: Option<&String>) -> TyFamily {
+        let mut arity = 0;
+        for segment in &path.segments {
+            arity += match &segment.args {
+                GenericArgs::None => 0,
+                GenericArgs::AngleBracketed(args) => {
+                    args.iter().filter(|a| !matches!(a, GenericArg::Lifetime(_))).count()
+                }
+                GenericArgs::Parenthesized { inputs, output } => inputs.len() + output.iter().len(),
+            };
+        }
+        let names = path.segments.iter().map(|s| s.name.as_str()).chain(marker.map(String::as_str));
+        TyFamily { path: intern(&names.collect::<Vec<_>>().join("::")), arity, args: true }
+    }
+
+    /// The full path of the type, eg `alloc::vec::Vec`. For types that aren't paths, this is
+    /// the name with the types inside it written as `_`, eg `&_` or `(_, _)`.
+    pub fn path(&self) -> &'static str {
+        self.path
+    }
+
+    /// How many generic arguments, or types inside the type, there are.
+    pub fn arity(&self) -> usize {
+        self.arity
+    }
+}
+
+impl fmt::Display for TyFamily {
+    /// Writes the name in the [default style](TyNameStyle::default_style), with each
+    /// argument written as `_`, eg `Vec<_>`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if !self.args || self.arity == 0 {
+            return f.write_str(&TyNameStyle::default_style().render(self.path));
+        }
+        let name = format!("{}<{}>", self.path, vec!["_"; self.arity].join(", "));
+        f.write_str(&TyNameStyle::default_style().render(&name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Ty, TyFamily};
+    use std::collections::HashSet;
+
+    fn family<T: ?Sized + 'static>() -> TyFamily {
+        Ty::of::<T>().family()
+    }
+
+    #[test]
+    fn families() {
+        assert_eq!(family::<Vec<u8>>(), family::<Vec<Vec<u8>>>());
+        assert_ne!(family::<Vec<u8>>(), family::<Option<u8>>());
+        assert_eq!(family::<Vec<u8>>().path(), "alloc::vec::Vec");
+        assert_eq!(family::<u8>().path(), "u8");
+        assert_eq!(family::<u8>().arity(), 0);
+        assert_eq!(family::<(u8, String)>(), family::<(String, u8)>());
+        assert_ne!(family::<(u8, u8)>(), family::<(u8, u8, u8)>());
+        assert_eq!(family::<[u8; 2]>(), family::<[String; 3]>());
+        assert_eq!(family::<&'static mut [u8]>().path(), "&mut _");
+        assert_eq!(family::<fn(u8) -> u8>().arity(), 2);
+        assert_eq!(family::<dyn Fn(u8) -> u8>().arity(), 2);
+        assert_eq!(family::<std::borrow::Cow<'static, str>>().arity(), 1);
+
+        let families: HashSet<_> = [family::<Vec<u8>>(), family::<Vec<bool>>(), family::<Box<u8>>()].into();
+        assert_eq!(families.len(), 2);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(family::<Vec<u8>>().to_string(), "Vec<_>");
+        assert_eq!(family::<Result<u8, String>>().to_string(), "Result<_, _>");
+        assert_eq!(family::<String>().to_string(), "String");
+        assert_eq!(family::<[u8; 2]>().to_string(), "[_; _]");
+        assert_eq!(family::<(u8, u8)>().to_string(), "(_, _)");
+        assert_eq!(family::<Box<dyn Fn(u8) -> u8>>().to_string(), "Box<_>");
+    }
+}
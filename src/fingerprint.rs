@@ -0,0 +1,142 @@
+use std::fmt;
+use crate::style::TyNameStyle;
+use crate::{LTy, Ty};
+
+/// A hash of a type that stays the same between builds & processes, unlike a
+/// [`TypeId`](std::any::TypeId), so it can be kept in caches on disk or sent to other programs.
+/// Returned by [`Ty::fingerprint`] & [`LTy::fingerprint`].
+///
+/// It's computed from the [canonical](crate::canonical) name, and the [layout](LTy::layout) of
+/// an `LTy`. Version 1 of the algorithm is the 64-bit [FNV-1a] hash of these bytes:
+/// 1. `ezty-fingerprint-v1:`
+/// 2. the canonical name, in UTF-8
+/// 3. for an `LTy`, a `0` byte, and then its size & alignment as little-endian `u64`s
+///
+/// The algorithm is only changed along with its [`VERSION`](Self::VERSION), which is part of
+/// the first bytes. That includes the table of canonical paths, so a change to it that renames
+/// a type also needs a new version. Types with the same fingerprint almost certainly have the
+/// same name, but the names come from [`type_name()`](std::any::type_name), which may change
+/// with the compiler version.
+/// ```
+/// # use ezty::{LTy, Ty};
+/// let fingerprint = Ty::of::<Vec<u8>>().fingerprint();
+/// assert_eq!(fingerprint.to_string(), "77de9005f93b50b4");
+/// assert_ne!(LTy::of::<Vec<u8>>().fingerprint(), fingerprint);
+/// ```
+///
+/// [FNV-1a]: http://www.isthe.com/chongo/tech/comp/fnv/index.html
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TyFingerprint(u64);
+
+impl TyFingerprint {
+    /// The version of the algorithm.
+    pub const VERSION: u32 = 1;
+
+    pub(crate) fn of_ty(ty: &Ty) -> TyFingerprint {
+        TyFingerprint(Fnv1a::start().write(Self::canonical_name(ty)).0)
+    }
+
+    pub(crate) fn of_lty(lty: &LTy) -> TyFingerprint {
+        let layout = lty.layout();
+        let hash = Fnv1a::start()
+            .write(Self::canonical_name(&lty.ty()))
+            .write(&[0])
+            .write(&(layout.size() as u64).to_le_bytes())
+            .write(&(layout.align() as u64).to_le_bytes());
+        TyFingerprint(hash.0)
+    }
+
+    fn canonical_name(ty: &Ty) -> &'static [u8] {
+        TyNameStyle::Canonical.render_static(ty.full_name()).as_bytes()
+    }
+
+    /// A fingerprint that was saved with [`as_u64`](Self::as_u64).
+    pub fn from_u64(hash: u64) -> Self {
+        TyFingerprint(hash)
+    }
+
+    pub fn as_u64(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Writes the fingerprint as 16 hex digits.
+impl fmt::Display for TyFingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
+impl fmt::Debug for TyFingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TyFingerprint({self})")
+    }
+}
+
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    fn start() -> Fnv1a {
+        Fnv1a(Self::OFFSET_BASIS).write(b"ezty-fingerprint-v1:")
+    }
+
+    fn write(mut self, bytes: &[u8]) -> Fnv1a {
+        for &byte in bytes {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(Self::PRIME);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Fnv1a, TyFingerprint};
+    use crate::{LTy, Ty};
+    use std::collections::{BTreeMap, HashMap};
+    use std::rc::Rc;
+    use std::sync::Mutex;
+
+    #[test]
+    fn fnv1a() {
+        assert_eq!(Fnv1a(Fnv1a::OFFSET_BASIS).write(b"").0, 0xcbf29ce484222325);
+        assert_eq!(Fnv1a(Fnv1a::OFFSET_BASIS).write(b"a").0, 0xaf63dc4c8601ec8c);
+        assert_eq!(Fnv1a(Fnv1a::OFFSET_BASIS).write(b"foobar").0, 0x85944171f73967e8);
+    }
+
+    #[test]
+    fn pinned() {
+        let fingerprint = |ty: Ty| ty.fingerprint().to_string();
+        assert_eq!(fingerprint(Ty::of::<u8>()), "07f41bfcff69fc25");
+        assert_eq!(fingerprint(Ty::of::<str>()), "de5093e5f8dbc7bf");
+        assert_eq!(fingerprint(Ty::of::<String>()), "bcae35c8f956a4a9");
+        assert_eq!(fingerprint(Ty::of::<Vec<u8>>()), "77de9005f93b50b4");
+        assert_eq!(fingerprint(Ty::of::<Option<&'static str>>()), "74a77c88db2e2518");
+        assert_eq!(fingerprint(Ty::of::<HashMap<String, (u8, [i32; 4])>>()), "750f985f159073e8");
+        assert_eq!(LTy::of::<u8>().fingerprint().to_string(), "6e81cc6c6ade447f");
+        assert_eq!(LTy::of::<[u8; 3]>().fingerprint().to_string(), "d1ab9ce49e8c30c3");
+    }
+
+    #[test]
+    fn pinned_canonical_names() {
+        // These are renamed by the canonical table, so its changes must bump the version.
+        let canonical = |ty: Ty| std::str::from_utf8(TyFingerprint::canonical_name(&ty)).unwrap();
+        assert_eq!(canonical(Ty::of::<Mutex<u8>>()), "std::sync::Mutex<u8>");
+        assert_eq!(Ty::of::<Mutex<u8>>().fingerprint().to_string(), "dc8312ac5f671dd4");
+        assert_eq!(canonical(Ty::of::<BTreeMap<u8, Rc<str>>>()), "std::collections::BTreeMap<u8, std::rc::Rc<str>>");
+        assert_eq!(Ty::of::<BTreeMap<u8, Rc<str>>>().fingerprint().to_string(), "bcc80935fb3d515b");
+    }
+
+    #[test]
+    fn distinct() {
+        assert_ne!(Ty::of::<u8>().fingerprint(), Ty::of::<i8>().fingerprint());
+        assert_ne!(Ty::of::<Vec<u8>>().fingerprint(), Ty::of::<Vec<u16>>().fingerprint());
+        assert_ne!(Ty::of::<u8>().fingerprint(), LTy::of::<u8>().fingerprint());
+        assert_eq!(LTy::of::<u8>().fingerprint(), LTy::of::<u8>().fingerprint());
+        let fingerprint = Ty::of::<String>().fingerprint();
+        assert_eq!(TyFingerprint::from_u64(fingerprint.as_u64()), fingerprint);
+        assert_eq!(format!("{fingerprint:?}"), format!("TyFingerprint({fingerprint})"));
+    }
+}
@@ -0,0 +1,306 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+use crate::parse::{Bound, GenericArg, GenericArgs, TypeName, TypePath};
+use crate::style::TyNameStyle;
+use crate::Ty;
+
+/// The structure of some types' names as a graph, for [Graphviz] or [Mermaid]. Returned by
+/// [`Ty::graph`].
+///
+/// Each type is a node, labelled with its name with the types inside it written as `_`, eg
+/// `HashMap<_, _>`. Edges lead to those types, and are labelled with their position: `0` for the
+/// first generic argument, `.1` for the second element of a tuple, `Item` for an associated
+/// type, `output` for a return type, `target` for what a reference points to, `element` for
+/// the elements of arrays & slices and `self` for the type of `<T as Trait>`. Types that appear
+/// more than once, even in different graphed types, share a node.
+/// ```
+/// # use ezty::Ty;
+/// assert_eq!(Ty::of::<Vec<(u8, u8)>>().graph().to_dot(), r#"digraph {
+///     node [shape=box, fontname=monospace];
+///     n0 [label="Vec<_>", penwidth=2];
+///     n1 [label="(_, _)"];
+///     n2 [label="u8"];
+///     n0 -> n1 [label="0"];
+///     n1 -> n2 [label=".0"];
+///     n1 -> n2 [label=".1"];
+/// }
+/// "#);
+/// ```
+///
+/// [Graphviz]: https://graphviz.org/doc/info/lang.html
+/// [Mermaid]: https://mermaid.js.org/syntax/flowchart.html
+#[derive(Clone, Debug)]
+pub struct TyGraph {
+    tys: Vec<Ty>,
+    style: TyNameStyle,
+}
+
+impl TyGraph {
+    /// Graphs all of `tys` together; their nodes are outlined more heavily.
+    pub fn of(tys: impl IntoIterator<Item = Ty>) -> Self {
+        TyGraph { tys: tys.into_iter().collect(), style: TyNameStyle::default_style() }
+    }
+
+    /// Also graphs `ty`.
+    pub fn with(mut self, ty: Ty) -> Self {
+        self.tys.push(ty);
+        self
+    }
+
+    /// Labels the nodes in this style, rather than the [default](TyNameStyle::default_style).
+    pub fn style(mut self, style: TyNameStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Writes the graph in the [DOT](https://graphviz.org/doc/info/lang.html) language.
+    pub fn to_dot(&self) -> String {
+        let graph = self.build();
+        let quote = |s: &str| s.replace('\\', "\\\\").replace('"', "\\\"");
+        let mut out = String::from("digraph {\n    node [shape=box, fontname=monospace];\n");
+        for (id, node) in graph.nodes.iter().enumerate() {
+            let bold = if graph.roots.contains(&id) { ", penwidth=2" } else { "" };
+            writeln!(out, "    n{id} [label=\"{}\"{bold}];", quote(node)).unwrap();
+        }
+        for (from, to, label) in &graph.edges {
+            writeln!(out, "    n{from} -> n{to} [label=\"{}\"];", quote(label)).unwrap();
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// Writes the graph as a [Mermaid flowchart](https://mermaid.js.org/syntax/flowchart.html).
+    pub fn to_mermaid(&self) -> String {
+        let graph = self.build();
+        // Mermaid labels may contain HTML, so brackets are written as entity codes.
+        let quote = |s: &str| {
+            let mut quoted = String::with_capacity(s.len());
+            for c in s.chars() {
+                match c {
+                    '"' | '<' | '>' | '&' | '#' => write!(quoted, "#{};", c as u32).unwrap(),
+                    c => quoted.push(c),
+                }
+            }
+            quoted
+        };
+        let mut out = String::from("flowchart TD\n");
+        for (id, node) in graph.nodes.iter().enumerate() {
+            if graph.roots.contains(&id) {
+                writeln!(out, "    n{id}[[\"{}\"]]", quote(node)).unwrap();
+            } else {
+                writeln!(out, "    n{id}[\"{}\"]", quote(node)).unwrap();
+            }
+        }
+        for (from, to, label) in &graph.edges {
+            writeln!(out, "    n{from} -->|\"{}\"| n{to}", quote(label)).unwrap();
+        }
+        out
+    }
+
+    fn build(&self) -> Graph {
+        let mut graph = Graph { style: self.style, ..Graph::default() };
+        for ty in &self.tys {
+            let id = match ty.parse() {
+                Ok(mut name) => {
+                    // The name before its paths are styled, which has the same shape.
+                    let mut full = name.clone();
+                    self.style.apply_with(&mut name, |name| full = name.clone());
+                    graph.add(&name, &full)
+                }
+                Err(_) => graph.node(ty.full_name().to_owned(), || ty.display(self.style).to_string()),
+            };
+            if !graph.roots.contains(&id) {
+                graph.roots.push(id);
+            }
+        }
+        graph.edges.sort_by_key(|&(from, _, _)| from);
+        graph
+    }
+}
+
+#[derive(Default)]
+struct Graph {
+    style: TyNameStyle,
+    /// The labels of the nodes.
+    nodes: Vec<String>,
+    /// The nodes of each type, by its full name.
+    ids: HashMap<String, usize>,
+    /// `(from, to, label)`.
+    edges: Vec<(usize, usize, String)>,
+    roots: Vec<usize>,
+}
+
+impl Graph {
+    /// Adds `name` and the types inside it, if they haven't been added already. `full` is `name`
+    /// with its paths in full, which tells apart types whose styled names are the same.
+    fn add(&mut self, name: &TypeName, full: &TypeName) -> usize {
+        let style = self.style;
+        let len = self.nodes.len();
+        let id = self.node(full.to_string(), || {
+            let mut skeleton = name.clone();
+            for child in skeleton.children_mut() {
+                *child = TypeName::Infer;
+            }
+            style.format(&skeleton)
+        });
+        if id == len {
+            let children = name.children().into_iter().zip(full.children());
+            for ((child, full_child), label) in children.zip(edge_labels(name)) {
+                let child = self.add(child, full_child);
+                self.edges.push((id, child, label));
+            }
+        }
+        id
+    }
+
+    /// The node for the type with the full name `key`, which is labelled with `label` if it's
+    /// new. A label that is empty is the same as the key.
+    fn node(&mut self, key: String, label: impl FnOnce() -> String) -> usize {
+        if let Some(&id) = self.ids.get(&key) {
+            return id;
+        }
+        let label = Some(label()).filter(|l| !l.is_empty()).unwrap_or_else(|| key.clone());
+        self.nodes.push(label);
+        self.ids.insert(key, self.nodes.len() - 1);
+        self.nodes.len() - 1
+    }
+}
+
+/// Labels for the edges to each of the [`children`](TypeName::children) of `name`.
+fn edge_labels(name: &TypeName) -> Vec<String> {
+    let mut labels = vec![];
+    match name {
+        TypeName::Path(path) | TypeName::Closure { parent: path, .. } => path_labels(path, &mut labels),
+        TypeName::Qualified { trait_, segments, .. } => {
+            labels.push("self".into());
+            if let Some(trait_) = trait_ {
+                path_labels(trait_, &mut labels);
+            }
+            for segment in segments {
+                args_labels(&segment.args, &mut labels);
+            }
+        }
+        TypeName::Ref { .. } | TypeName::Ptr { .. } => labels.push("target".into()),
+        TypeName::Array { .. } | TypeName::Slice(_) => labels.push("element".into()),
+        TypeName::Tuple(tys) => labels.extend((0..tys.len()).map(|i| format!(".{i}"))),
+        TypeName::Fn(sig) => {
+            labels.extend((0..sig.inputs.len()).map(|i| i.to_string()));
+            labels.extend(sig.output.iter().map(|_| "output".into()));
+        }
+        TypeName::Dyn(bounds) | TypeName::Impl(bounds) => {
+            for bound in bounds {
+                if let Bound::Trait { path, .. } = bound {
+                    path_labels(path, &mut labels);
+                }
+            }
+        }
+        TypeName::Never | TypeName::Infer => (),
+    }
+    labels
+}
+
+fn path_labels(path: &TypePath, labels: &mut Vec<String>) {
+    for segment in &path.segments {
+        args_labels(&segment.args, labels);
+    }
+}
+
+fn args_labels(args: &GenericArgs, labels: &mut Vec<String>) {
+    match args {
+        GenericArgs::None => (),
+        GenericArgs::AngleBracketed(args) => {
+            let mut i = 0;
+            for arg in args {
+                match arg {
+                    GenericArg::Type(_) => {
+                        labels.push(i.to_string());
+                        i += 1;
+                    }
+                    GenericArg::Binding { name, .. } => labels.push(name.clone()),
+                    GenericArg::Lifetime(_) | GenericArg::Const(_) => (),
+                }
+            }
+        }
+        GenericArgs::Parenthesized { inputs, output } => {
+            labels.extend((0..inputs.len()).map(|i| i.to_string()));
+            labels.extend(output.iter().map(|_| "output".into()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TyGraph;
+    use crate::{Ty, TyNameStyle};
+    use std::collections::HashMap;
+
+    mod a {
+        pub struct Row;
+    }
+    mod b {
+        pub struct Row;
+    }
+
+    #[test]
+    fn dot() {
+        let graph = TyGraph::of([Ty::of::<HashMap<&'static str, [Option<u8>; 2]>>(), Ty::of::<fn(u8) -> Option<u8>>()])
+            .style(TyNameStyle::Pretty);
+        assert_eq!(graph.to_dot(), r#"digraph {
+    node [shape=box, fontname=monospace];
+    n0 [label="HashMap<_, _>", penwidth=2];
+    n1 [label="&_"];
+    n2 [label="str"];
+    n3 [label="[_; 2]"];
+    n4 [label="Option<_>"];
+    n5 [label="u8"];
+    n6 [label="fn(_) -> _", penwidth=2];
+    n0 -> n1 [label="0"];
+    n0 -> n3 [label="1"];
+    n1 -> n2 [label="target"];
+    n3 -> n4 [label="element"];
+    n4 -> n5 [label="0"];
+    n6 -> n5 [label="0"];
+    n6 -> n4 [label="output"];
+}
+"#);
+    }
+
+    #[test]
+    fn mermaid() {
+        let graph = Ty::of::<Box<dyn Iterator<Item = (u8, &'static str)>>>()
+            .graph()
+            .with(Ty::of::<u8>())
+            .style(TyNameStyle::Pretty);
+        assert_eq!(graph.to_mermaid(), r##"flowchart TD
+    n0[["Box#60;_#62;"]]
+    n1["dyn Iterator#60;Item = _#62;"]
+    n2["(_, _)"]
+    n3[["u8"]]
+    n4["#38;_"]
+    n5["str"]
+    n0 -->|"0"| n1
+    n1 -->|"Item"| n2
+    n2 -->|".0"| n3
+    n2 -->|".1"| n4
+    n4 -->|"target"| n5
+"##);
+    }
+
+    #[test]
+    fn same_short_names() {
+        let graph = TyGraph::of([Ty::of::<Vec<a::Row>>(), Ty::of::<Vec<b::Row>>(), Ty::of::<Option<a::Row>>()])
+            .style(TyNameStyle::Short);
+        assert_eq!(graph.to_dot(), r#"digraph {
+    node [shape=box, fontname=monospace];
+    n0 [label="Vec<_>", penwidth=2];
+    n1 [label="Row"];
+    n2 [label="Vec<_>", penwidth=2];
+    n3 [label="Row"];
+    n4 [label="Option<_>", penwidth=2];
+    n0 -> n1 [label="0"];
+    n2 -> n3 [label="0"];
+    n4 -> n1 [label="0"];
+}
+"#);
+    }
+}
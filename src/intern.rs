@@ -0,0 +1,31 @@
+use std::collections::HashSet;
+use std::sync::{Mutex, OnceLock};
+
+/// Returns a `'static` copy of `s`.
+///
+/// Each distinct string is leaked only once, so this is for things like type names, which there
+/// are a bounded number of.
+pub(crate) fn intern(s: &str) -> &'static str {
+    static INTERNED: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+    let mut interned = INTERNED.get_or_init(Default::default).lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(&s) = interned.get(s) {
+        return s;
+    }
+    let s: &'static str = Box::leak(s.into());
+    interned.insert(s);
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::intern;
+
+    #[test]
+    fn dedup() {
+        let a = intern(&String::from("hello"));
+        let b = intern(&String::from("hello"));
+        assert_eq!(a, "hello");
+        assert!(std::ptr::eq(a, b));
+        assert!(!std::ptr::eq(a, intern("hello!")));
+    }
+}
@@ -0,0 +1,173 @@
+use crate::parse::TypeName;
+use crate::LTy;
+
+/// What sort of type a [`Ty`](crate::Ty) is, judging by its name. Returned by
+/// [`Ty::kind`](crate::Ty::kind).
+///
+/// [`LTy`] can say a little more, as it knows the [size](LTy::is_zero_sized) of the type.
+/// ```
+/// # use ezty::{Ty, TyKind};
+/// assert_eq!(Ty::of::<&mut [u8]>().kind(), TyKind::Ref { mutable: true });
+/// assert_eq!(Ty::of::<[u8; 4]>().kind(), TyKind::Array { len: Some(4) });
+/// assert_eq!(Ty::of::<(u8, char)>().kind(), TyKind::Tuple { arity: 2 });
+/// assert_eq!(Ty::of::<Vec<u8>>().kind(), TyKind::Named);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TyKind {
+    /// `bool`, `char`, `str` or a number.
+    Primitive,
+    /// `()`. Other tuples are [`Tuple`](Self::Tuple)s.
+    Unit,
+    /// `!`.
+    Never,
+    /// `&T` or `&mut T`.
+    Ref { mutable: bool },
+    /// `*const T` or `*mut T`.
+    Ptr { mutable: bool },
+    /// `[T]`.
+    Slice,
+    /// `[T; N]`. The length is `None` if it isn't written as a number.
+    Array { len: Option<usize> },
+    /// `(A,)`, `(A, B)`…
+    Tuple { arity: usize },
+    /// `fn(A) -> B`.
+    FnPtr,
+    /// A closure, async block or coroutine.
+    Closure,
+    /// `dyn Trait`.
+    TraitObject,
+    /// `impl Trait`.
+    Opaque,
+    /// A struct, enum or union, or a function item, eg `alloc::vec::Vec<u8>`.
+    Named,
+    /// Anything else, such as `<T as Trait>::Assoc`, or a name that can't be parsed.
+    Other,
+}
+
+impl TyKind {
+    pub(crate) fn of(name: &TypeName) -> TyKind {
+        match name {
+            TypeName::Path(path) => match &path.segments[..] {
+                [segment] if PRIMITIVES.contains(&&*segment.name) => TyKind::Primitive,
+                _ => TyKind::Named,
+            },
+            TypeName::Tuple(tys) if tys.is_empty() => TyKind::Unit,
+            TypeName::Tuple(tys) => TyKind::Tuple { arity: tys.len() },
+            TypeName::Never => TyKind::Never,
+            &TypeName::Ref { mutable, .. } => TyKind::Ref { mutable },
+            &TypeName::Ptr { mutable, .. } => TyKind::Ptr { mutable },
+            TypeName::Slice(_) => TyKind::Slice,
+            TypeName::Array { len, .. } => TyKind::Array { len: len.trim_end_matches("usize").parse().ok() },
+            TypeName::Fn(_) => TyKind::FnPtr,
+            TypeName::Closure { .. } => TyKind::Closure,
+            TypeName::Dyn(_) => TyKind::TraitObject,
+            TypeName::Impl(_) => TyKind::Opaque,
+            TypeName::Qualified { .. } | TypeName::Infer => TyKind::Other,
+        }
+    }
+
+    /// Is this a reference or a raw pointer?
+    pub fn is_pointer(&self) -> bool {
+        matches!(self, TyKind::Ref { .. } | TyKind::Ptr { .. })
+    }
+}
+
+impl LTy {
+    /// Does the type take up no space, like `()`, `[u8; 0]` or `PhantomData<T>`?
+    pub fn is_zero_sized(&self) -> bool {
+        self.layout.size() == 0
+    }
+
+    /// Is this a pointer with metadata, such as `&str`, `&[T]` or `*const dyn Trait`, which is
+    /// twice the size of a thin pointer?
+    pub fn is_wide_pointer(&self) -> bool {
+        self.kind().is_pointer() && self.layout.size() == 2 * std::mem::size_of::<usize>()
+    }
+}
+
+pub(crate) const PRIMITIVES: &[&str] = &[
+    "bool", "char", "str", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128",
+    "isize", "f16", "f32", "f64", "f128",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::TyKind;
+    use crate::{LTy, Ty};
+    use std::any::Any;
+    use std::cell::RefCell;
+    use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+    use std::marker::PhantomData;
+    use std::num::NonZeroU8;
+    use std::rc::Rc;
+    use std::sync::{Arc, Mutex};
+
+    fn kind<T: ?Sized + 'static>() -> TyKind {
+        Ty::of::<T>().kind()
+    }
+
+    #[test]
+    fn std_types() {
+        for ty in [
+            kind::<bool>(), kind::<char>(), kind::<str>(), kind::<u8>(), kind::<u16>(), kind::<u32>(),
+            kind::<u64>(), kind::<u128>(), kind::<usize>(), kind::<i8>(), kind::<i16>(), kind::<i32>(),
+            kind::<i64>(), kind::<i128>(), kind::<isize>(), kind::<f32>(), kind::<f64>(),
+        ] {
+            assert_eq!(ty, TyKind::Primitive);
+        }
+        for ty in [
+            kind::<String>(), kind::<Vec<u8>>(), kind::<Option<&'static str>>(), kind::<Result<(), ()>>(),
+            kind::<Box<dyn Any>>(), kind::<Rc<str>>(), kind::<Arc<Mutex<u8>>>(), kind::<RefCell<u8>>(),
+            kind::<HashMap<u8, u8>>(), kind::<HashSet<u8>>(), kind::<BTreeMap<u8, u8>>(),
+            kind::<VecDeque<u8>>(), kind::<PhantomData<u8>>(), kind::<NonZeroU8>(),
+            kind::<std::time::Duration>(), kind::<std::path::PathBuf>(),
+        ] {
+            assert_eq!(ty, TyKind::Named);
+        }
+        assert_eq!(kind::<()>(), TyKind::Unit);
+        assert_eq!(kind::<(u8,)>(), TyKind::Tuple { arity: 1 });
+        assert_eq!(kind::<(u8, (), String)>(), TyKind::Tuple { arity: 3 });
+        assert_eq!(kind::<&'static str>(), TyKind::Ref { mutable: false });
+        assert_eq!(kind::<&'static mut Vec<u8>>(), TyKind::Ref { mutable: true });
+        assert_eq!(kind::<*const u8>(), TyKind::Ptr { mutable: false });
+        assert_eq!(kind::<*mut [u8]>(), TyKind::Ptr { mutable: true });
+        assert_eq!(kind::<[u8]>(), TyKind::Slice);
+        assert_eq!(kind::<[u8; 0]>(), TyKind::Array { len: Some(0) });
+        assert_eq!(kind::<[[u8; 2]; 3]>(), TyKind::Array { len: Some(3) });
+        assert_eq!(kind::<fn()>(), TyKind::FnPtr);
+        assert_eq!(kind::<unsafe extern "C" fn(u8) -> u8>(), TyKind::FnPtr);
+        assert_eq!(kind::<dyn Any>(), TyKind::TraitObject);
+        assert_eq!(kind::<dyn Fn(u8) + Send>(), TyKind::TraitObject);
+        assert_eq!(TyKind::of(&"!".parse().unwrap()), TyKind::Never);
+        assert_eq!(TyKind::of(&"<u8 as m::Trait>::Assoc".parse().unwrap()), TyKind::Other);
+        assert_eq!(TyKind::of(&"impl Iterator<Item = u8>".parse().unwrap()), TyKind::Opaque);
+    }
+
+    #[test]
+    fn closures() {
+        fn kind_of<T: 'static>(_: &T) -> TyKind {
+            Ty::of::<T>().kind()
+        }
+        assert_eq!(kind_of(&|| ()), TyKind::Closure);
+        assert_eq!(kind_of(&async {}), TyKind::Closure);
+        assert_eq!(kind_of(&closures), TyKind::Named);
+        assert!(kind::<&'static u8>().is_pointer());
+        assert!(kind::<*mut u8>().is_pointer());
+        assert!(!kind::<Box<u8>>().is_pointer());
+    }
+
+    #[test]
+    fn layouts() {
+        assert!(LTy::of::<()>().is_zero_sized());
+        assert!(LTy::of::<[u8; 0]>().is_zero_sized());
+        assert!(LTy::of::<PhantomData<String>>().is_zero_sized());
+        assert!(!LTy::of::<u8>().is_zero_sized());
+        assert!(!LTy::of::<Option<()>>().is_zero_sized());
+        assert!(LTy::of::<&'static str>().is_wide_pointer());
+        assert!(LTy::of::<*const [u8]>().is_wide_pointer());
+        assert!(LTy::of::<&'static dyn Any>().is_wide_pointer());
+        assert!(!LTy::of::<&'static u8>().is_wide_pointer());
+        assert!(!LTy::of::<Box<str>>().is_wide_pointer());
+        assert!(!LTy::of::<(usize, usize)>().is_wide_pointer());
+    }
+}
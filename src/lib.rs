@@ -29,19 +29,83 @@ impl Ty {
     pub fn of<T: ?Sized + 'static>() -> Ty {
         Ty {
             id: TypeId::of::<T>(),
-            name: type_name::<T>,
+            name: std::any::type_name::<T>,
         }
     }
     pub fn of_every<T: ?Sized>() -> Ty {
         Ty {
             id: TypeId::of_every::<T>(),
-            name: type_name::<T>,
+            name: std::any::type_name::<T>,
         }
     }
 }
 impl Ty {
-    pub fn name(&self) -> &'static str { (self.name)() }
+    /// The [`pretty`] name. It is only rendered once, so this is cheap.
+    pub fn name(&self) -> &'static str { TyNameStyle::Pretty.render_static((self.name)()) }
+    /// The unmodified [`type_name()`](std::any::type_name).
+    pub fn full_name(&self) -> &'static str { (self.name)() }
     pub fn id(&self) -> TypeId { self.id }
+    /// Formats the name in the given style.
+    pub fn display(&self, style: TyNameStyle) -> TyDisplay {
+        TyDisplay { ty: *self, style, abbreviation: Abbreviation::default() }
+    }
+    /// Formats the name for a terminal, with colours and optional links to documentation.
+    pub fn terminal(&self) -> TyTerminal {
+        TyTerminal { ty: *self, style: TyNameStyle::default_style(), links: false, docs_url: None }
+    }
+    /// Graphs the structure of the name, for Graphviz or Mermaid. More types can be
+    /// [added](TyGraph::with).
+    pub fn graph(&self) -> TyGraph {
+        TyGraph::of([*self])
+    }
+    /// Finds where the names of two types differ. See also [`assert_ty_eq!`].
+    pub fn diff(&self, other: &Ty) -> TyDiff {
+        TyDiff::new(self, other)
+    }
+}
+impl Ty {
+    /// Parses the full [`type_name()`](std::any::type_name) into a [`TypeName`].
+    pub fn parse(&self) -> Result<TypeName, ParseError> {
+        TypeName::parse((self.name)())
+    }
+    /// The crate the type is defined in, eg `"alloc"` for `Vec<u8>`. Primitives have none.
+    pub fn crate_name(&self) -> Option<String> {
+        self.parse().ok()?.crate_name().map(str::to_owned)
+    }
+    /// The path of the module the type is defined in, eg `"alloc::vec"` for `Vec<u8>`.
+    pub fn module_path(&self) -> Option<String> {
+        self.parse().ok()?.module_path()
+    }
+    /// The name of the type without its path or generics, eg `"Vec"` for `Vec<u8>`.
+    pub fn base_name(&self) -> Option<String> {
+        self.parse().ok()?.base_name().map(str::to_owned)
+    }
+    /// The generic arguments of the type, eg `[u8]` for `Vec<u8>`.
+    pub fn generic_args(&self) -> Vec<GenericArg> {
+        match self.parse() {
+            Ok(TypeName::Path(mut path)) => match path.segments.pop().map(|s| s.args) {
+                Some(GenericArgs::AngleBracketed(args)) => args,
+                _ => vec![],
+            },
+            _ => vec![],
+        }
+    }
+    /// The type with its generic arguments erased, eg `alloc::vec::Vec` with 1 argument.
+    pub fn family(&self) -> TyFamily {
+        TyFamily::of(self)
+    }
+    /// What sort of type this is, eg a reference or a tuple, judging by its name.
+    pub fn kind(&self) -> TyKind {
+        self.parse().map_or(TyKind::Other, |name| TyKind::of(&name))
+    }
+    /// A hash of the [canonical] name that is the same in every build.
+    pub fn fingerprint(&self) -> TyFingerprint {
+        TyFingerprint::of_ty(self)
+    }
+    /// Are both types instances of the same generic type, eg `Vec<u8>` & `Vec<String>`?
+    pub fn same_family(&self, other: &Ty) -> bool {
+        self.family() == other.family()
+    }
 }
 impl hash::Hash for Ty {
     fn hash<H: hash::Hasher>(&self, state: &mut H) {
@@ -60,12 +124,16 @@ impl Ord for Ty {
 }
 impl PartialOrd for Ty {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.id.partial_cmp(&other.id)
+        Some(self.cmp(other))
     }
 }
 impl fmt::Debug for Ty {
+    /// Writes the name in the [default style](TyNameStyle::default_style), or in the
+    /// [full style](TyNameStyle::Full) for `{:#?}`. A precision, as in `{:.40?}`,
+    /// [abbreviates](abbreviate) the name to that width.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", (self.name)())
+        let style = if f.alternate() { TyNameStyle::Full } else { TyNameStyle::default_style() };
+        fmt::Display::fmt(&self.display(style), f)
     }
 }
 
@@ -109,18 +177,73 @@ impl TypeId {
 pub struct NonStaticTypeId(usize);
 impl NonStaticTypeId {
     pub fn of<T: ?Sized>() -> Self {
-        Self(Self::of::<T> as usize)
+        Self(Self::of::<T> as *const () as usize)
     }
 }
 
 
 /// Returns the prettified name of a type, eg `"Vec<T>"` rather than `"alloc::vec::Vec<T>"`.
+///
+/// The name is only rendered once per type; later calls are cheap.
 pub fn type_name<T: ?Sized>() -> &'static str {
-    pretty(std::any::type_name::<T>())
+    TyNameStyle::Pretty.render_static(std::any::type_name::<T>())
 }
 
 mod pretty_impl;
-pub use self::pretty_impl::pretty;
+pub use self::pretty_impl::{pretty, canonical, fn_signature, FnSignature};
+
+mod demangle;
+pub use self::demangle::demangle;
+
+mod text;
+pub use self::text::prettify_text;
+
+mod alias;
+pub use self::alias::Param;
+mod rules;
+pub use self::rules::{PrettyRules, PrettyRulesGuard};
+
+mod intern;
+
+mod terminal;
+pub use self::terminal::{TyTerminal, STD_DOCS_URL};
+
+mod registry;
+pub use self::registry::TyRegistry;
+mod linked;
+
+mod kind;
+pub use self::kind::TyKind;
+
+mod family;
+pub use self::family::TyFamily;
+
+mod fingerprint;
+pub use self::fingerprint::TyFingerprint;
+
+mod suggest;
+pub use self::suggest::{suggest, Suggestion, UnknownTy};
+
+mod pattern;
+pub use self::pattern::TyPattern;
+
+mod graph;
+pub use self::graph::TyGraph;
+
+mod diff;
+pub use self::diff::TyDiff;
+
+mod unique;
+pub use self::unique::{unambiguous_names, name_collisions, NameCollision};
+
+mod abbrev;
+pub use self::abbrev::{abbreviate, Abbreviation};
+
+mod style;
+pub use self::style::{TyNameStyle, TyDisplay, UnknownStyle, TY_NAME_STYLE_VAR};
+
+mod parse;
+pub use self::parse::{TypeName, TypePath, PathSegment, GenericArgs, GenericArg, FnSig, Bound, ParseError};
 
 
 
@@ -133,6 +256,11 @@ mod any_debug {
 }
 pub use self::any_debug::AnyDebug;
 
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(feature = "serde")]
+pub use self::serde_impl::TySeed;
+
 /// Just like [`Ty`] but it also includes [`Layout`] information.
 #[derive(Clone, Eq, PartialEq)]
 pub struct LTy {
@@ -141,7 +269,7 @@ pub struct LTy {
 }
 impl fmt::Debug for LTy {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", (self.ty.name)())
+        fmt::Debug::fmt(&self.ty, f)
     }
 }
 impl LTy {
@@ -161,8 +289,21 @@ impl LTy {
 impl LTy {
     pub fn ty(&self) -> Ty { self.ty }
     pub fn layout(&self) -> Layout { self.layout }
-    pub fn name(&self) -> &'static str { (self.ty.name)() }
+    pub fn name(&self) -> &'static str { self.ty.name() }
+    pub fn full_name(&self) -> &'static str { self.ty.full_name() }
     pub fn id(&self) -> TypeId { self.ty.id() }
+    /// Formats the name in the given style.
+    pub fn display(&self, style: TyNameStyle) -> TyDisplay { self.ty.display(style) }
+    /// Formats the name for a terminal. See [`Ty::terminal`].
+    pub fn terminal(&self) -> TyTerminal { self.ty.terminal() }
+    /// Graphs the structure of the name. See [`Ty::graph`].
+    pub fn graph(&self) -> TyGraph { self.ty.graph() }
+    /// The type with its generic arguments erased. See [`Ty::family`].
+    pub fn family(&self) -> TyFamily { self.ty.family() }
+    /// What sort of type this is. See [`Ty::kind`].
+    pub fn kind(&self) -> TyKind { self.ty.kind() }
+    /// A hash of the [canonical] name & the layout that is the same in every build.
+    pub fn fingerprint(&self) -> TyFingerprint { TyFingerprint::of_lty(self) }
 }
 
 #[cfg(test)]
@@ -197,10 +338,35 @@ mod tests {
 
     #[test]
     fn less_pretty() {
-        // FIXME: pretty should return a String.
         let a = Ty::of::<Vec<Vec<u8>>>();
         let a = format!("{:?}", a);
         println!("{}", a);
         assert_eq!(a, "Vec<Vec<u8>>");
     }
+
+    #[test]
+    fn nested_pretty() {
+        use std::collections::HashMap;
+        let a = Ty::of::<HashMap<Box<u8>, (Vec<u8>, [Option<u8>; 2])>>();
+        assert_eq!(a.name(), "HashMap<Box<u8>, (Vec<u8>, [Option<u8>; 2])>");
+        let a = super::LTy::of::<&'static [Vec<u8>]>();
+        assert_eq!(format!("{:?}", a), "&[Vec<u8>]");
+    }
+
+    #[test]
+    fn parsed() {
+        use super::GenericArg;
+        let a = Ty::of::<Vec<Option<u8>>>();
+        assert_eq!(a.crate_name().as_deref(), Some("alloc"));
+        assert_eq!(a.module_path().as_deref(), Some("alloc::vec"));
+        assert_eq!(a.base_name().as_deref(), Some("Vec"));
+        let [GenericArg::Type(arg)] = &a.generic_args()[..] else { panic!() };
+        assert_eq!(arg.base_name(), Some("Option"));
+        assert_eq!(a.parse().unwrap().to_string(), std::any::type_name::<Vec<Option<u8>>>());
+
+        let a = Ty::of::<u8>();
+        assert_eq!(a.crate_name(), None);
+        assert_eq!(a.base_name().as_deref(), Some("u8"));
+        assert!(a.generic_args().is_empty());
+    }
 }
@@ -0,0 +1,173 @@
+use std::sync::OnceLock;
+use crate::registry::TyRegistry;
+use crate::LTy;
+
+/// The types from every [`register!`](crate::register), and from
+/// [`install_linked`](TyRegistry::install_linked).
+static LINKED: OnceLock<TyRegistry> = OnceLock::new();
+static INSTALLED: OnceLock<Vec<LTy>> = OnceLock::new();
+
+impl TyRegistry {
+    /// Does [`register!`](crate::register) work on this platform? It needs the linker to collect
+    /// the `ezty_types` section, which ELF linkers do; elsewhere, use
+    /// [`install_linked`](Self::install_linked).
+    pub const LINK_SECTIONS: bool = COLLECTED;
+
+    /// The types from every [`register!`](crate::register) in the program, which are found the
+    /// first time this is called. They are also in the [`global`](Self::global) registry.
+    /// ```
+    /// # use ezty::{register, Ty, TyRegistry};
+    /// struct Player;
+    /// register!(Player);
+    ///
+    /// # if TyRegistry::LINK_SECTIONS {
+    /// assert!(TyRegistry::linked().tys().contains(&Ty::of::<Player>()));
+    /// assert!(TyRegistry::global().by_name(std::any::type_name::<Player>()).is_some());
+    /// # }
+    /// ```
+    pub fn linked() -> &'static TyRegistry {
+        LINKED.get_or_init(|| {
+            // So that `install_linked` fails from now on.
+            let installed = INSTALLED.get_or_init(Vec::new);
+            let registry = TyRegistry::new();
+            for register in section() {
+                let lty = register();
+                if lty.ty() != crate::Ty::of::<Sentinel>() {
+                    registry.register_lty(lty);
+                }
+            }
+            for lty in installed {
+                registry.register_lty(lty.clone());
+            }
+            registry
+        })
+    }
+
+    /// Adds types to the [`linked`](Self::linked) registry by hand, for platforms where
+    /// [`register!`](crate::register) doesn't work.
+    ///
+    /// This can only be done once, and must be done before the linked or
+    /// [`global`](Self::global) registries are used.
+    /// ```
+    /// # use ezty::{LTy, Ty, TyRegistry};
+    /// struct Player;
+    /// TyRegistry::install_linked(vec![LTy::of::<Player>()]).unwrap();
+    /// assert!(TyRegistry::linked().tys().contains(&Ty::of::<Player>()));
+    /// ```
+    pub fn install_linked(tys: Vec<LTy>) -> Result<(), Vec<LTy>> {
+        INSTALLED.set(tys)
+    }
+}
+
+/// Registers types with the [`linked`](TyRegistry::linked) & [`global`](TyRegistry::global)
+/// registries from anywhere in the program, when it's linked.
+///
+/// Each type is put in the `ezty_types` linker section, which is collected the first time
+/// either registry is used. This doesn't work on every platform; see
+/// [`LINK_SECTIONS`](TyRegistry::LINK_SECTIONS). The linker also leaves out crates that
+/// nothing else in the program uses, along with their registrations.
+/// ```
+/// struct Player;
+/// struct Team(Vec<Player>);
+/// ezty::register!(Player, Team);
+/// ```
+#[macro_export]
+macro_rules! register {
+    ($($ty:ty),* $(,)?) => {
+        $(const _: () = {
+            $crate::__link_sections! {
+                if {
+                    #[used]
+                    #[link_section = "ezty_types"]
+                    static REGISTER: fn() -> $crate::LTy = $crate::LTy::of::<$ty>;
+                } else {
+                    #[used]
+                    static REGISTER: fn() -> $crate::LTy = $crate::LTy::of::<$ty>;
+                }
+            }
+        };)*
+    };
+}
+
+/// Keeps the items of the first block on the platforms whose linkers collect the `ezty_types`
+/// section, and those of the second elsewhere.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __link_sections {
+    (if { $($yes:item)* } else { $($no:item)* }) => {
+        $crate::__link_sections! {
+            @cfg any(
+                target_os = "linux",
+                target_os = "android",
+                target_os = "freebsd",
+                target_os = "dragonfly",
+                target_os = "netbsd",
+                target_os = "openbsd",
+                target_os = "illumos",
+                target_os = "fuchsia",
+            ),
+            { $($yes)* },
+            { $($no)* }
+        }
+    };
+    (@cfg $cfg:meta, { $($yes:item)* }, { $($no:item)* }) => {
+        $(#[cfg($cfg)] $yes)*
+        $(#[cfg(not($cfg))] $no)*
+    };
+}
+
+/// Always in the section, so that it exists.
+struct Sentinel;
+register!(Sentinel);
+
+__link_sections! {
+    if {
+        const COLLECTED: bool = true;
+
+        /// Everything in the `ezty_types` section.
+        fn section() -> &'static [fn() -> LTy] {
+            // The linker defines these at the start & end of the section.
+            extern "C" {
+                #[link_name = "__start_ezty_types"]
+                static START: u8;
+                #[link_name = "__stop_ezty_types"]
+                static STOP: u8;
+            }
+            // SAFETY: the section is made only of the `fn() -> LTy`s from `register!`, and
+            // contains at least the `Sentinel`.
+            unsafe {
+                let start = (&raw const START).cast::<fn() -> LTy>();
+                let stop = (&raw const STOP).cast::<fn() -> LTy>();
+                std::slice::from_raw_parts(start, stop.offset_from(start) as usize)
+            }
+        }
+    } else {
+        const COLLECTED: bool = false;
+
+        fn section() -> &'static [fn() -> LTy] {
+            &[]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{LTy, Ty, TyRegistry};
+
+    struct Linked;
+    struct Generic<T>(T);
+    register!(Linked, Generic<Linked>);
+
+    #[test]
+    fn linked() {
+        let linked = TyRegistry::linked();
+        assert!(TyRegistry::install_linked(vec![]).is_err());
+        if !TyRegistry::LINK_SECTIONS {
+            return;
+        }
+        assert!(linked.tys().contains(&Ty::of::<Linked>()));
+        assert_eq!(linked.lty(Ty::of::<Generic<Linked>>()), Some(LTy::of::<Generic<Linked>>()));
+        assert!(!linked.tys().contains(&Ty::of::<super::Sentinel>()));
+        assert!(TyRegistry::global().by_type_id(std::any::TypeId::of::<Linked>()).is_some());
+    }
+}
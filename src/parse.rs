@@ -0,0 +1,1212 @@
+//! A parser for the strings produced by [`type_name()`](std::any::type_name).
+
+use std::fmt;
+use std::str::FromStr;
+
+/// A parsed [`type_name()`](std::any::type_name).
+///
+/// Displaying a `TypeName` reproduces the string it was parsed from.
+/// ```
+/// # use ezty::TypeName;
+/// let name = "core::option::Option<&'_ mut [alloc::vec::Vec<u8>; 3]>";
+/// let parsed: TypeName = name.parse().unwrap();
+/// assert_eq!(parsed.base_name(), Some("Option"));
+/// assert_eq!(parsed.to_string(), name);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TypeName {
+    /// `u8`, `alloc::vec::Vec<u8>`.
+    Path(TypePath),
+    /// `<T as Trait>::Assoc`.
+    Qualified {
+        self_ty: Box<TypeName>,
+        trait_: Option<TypePath>,
+        segments: Vec<PathSegment>,
+    },
+    /// `&T`, `&'a mut T`.
+    Ref {
+        lifetime: Option<String>,
+        mutable: bool,
+        ty: Box<TypeName>,
+    },
+    /// `*const T`, `*mut T`.
+    Ptr {
+        mutable: bool,
+        ty: Box<TypeName>,
+    },
+    /// `()`, `(T,)`, `(A, B)`.
+    Tuple(Vec<TypeName>),
+    /// `[T; N]`. The length is kept as written.
+    Array {
+        ty: Box<TypeName>,
+        len: String,
+    },
+    /// `[T]`.
+    Slice(Box<TypeName>),
+    /// `fn(A) -> B`.
+    Fn(FnSig),
+    /// `dyn A + B`.
+    Dyn(Vec<Bound>),
+    /// `impl A + B`.
+    Impl(Vec<Bound>),
+    /// A closure or async block, eg `my_crate::main::{{closure}}`.
+    Closure {
+        /// The item that the closure was defined in.
+        parent: TypePath,
+        /// The closure's path segment, eg `{{closure}}`.
+        marker: String,
+    },
+    /// `!`.
+    Never,
+    /// `_`.
+    Infer,
+}
+
+/// A `::`-separated path, eg `alloc::vec::Vec<u8>`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TypePath {
+    pub segments: Vec<PathSegment>,
+}
+
+/// One segment of a [`TypePath`], eg `Vec<u8>`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PathSegment {
+    pub name: String,
+    pub args: GenericArgs,
+}
+
+/// The generic arguments of a [`PathSegment`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub enum GenericArgs {
+    #[default]
+    None,
+    /// `<A, B>`.
+    AngleBracketed(Vec<GenericArg>),
+    /// `(A, B) -> C`, as in `Fn(A, B) -> C`.
+    Parenthesized {
+        inputs: Vec<TypeName>,
+        output: Option<Box<TypeName>>,
+    },
+}
+
+/// A single generic argument.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GenericArg {
+    Type(TypeName),
+    /// `'_`.
+    Lifetime(String),
+    /// `3`, `true`, `'x'`. Kept as written.
+    Const(String),
+    /// `Item = u8`.
+    Binding {
+        name: String,
+        ty: TypeName,
+    },
+}
+
+/// The signature of a function pointer, eg `unsafe extern "C" fn(u8, ...) -> u8`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct FnSig {
+    /// The lifetimes of a `for<'a>` binder.
+    pub for_lifetimes: Vec<String>,
+    pub is_unsafe: bool,
+    /// `None` for Rust functions, `Some("")` for a bare `extern`, and `Some("C")` for `extern "C"`.
+    pub abi: Option<String>,
+    pub inputs: Vec<TypeName>,
+    pub variadic: bool,
+    pub output: Option<Box<TypeName>>,
+}
+
+/// A bound of a [`TypeName::Dyn`] or [`TypeName::Impl`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Bound {
+    Trait {
+        /// The lifetimes of a `for<'a>` binder.
+        for_lifetimes: Vec<String>,
+        path: TypePath,
+    },
+    Lifetime(String),
+}
+
+/// The error returned when a type name can't be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// The byte offset of the problem.
+    pub pos: usize,
+    pub msg: &'static str,
+}
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at byte {}", self.msg, self.pos)
+    }
+}
+impl std::error::Error for ParseError {}
+
+impl TypeName {
+    /// Parses the output of [`type_name()`](std::any::type_name).
+    pub fn parse(name: &str) -> Result<TypeName, ParseError> {
+        let mut p = Parser { src: name, pos: 0, depth: 0 };
+        let ty = p.ty()?;
+        p.skip_ws();
+        if p.pos != name.len() {
+            return Err(p.error("unexpected trailing characters"));
+        }
+        Ok(ty)
+    }
+
+    /// Returns the path, if this is a [`TypeName::Path`].
+    pub fn path(&self) -> Option<&TypePath> {
+        match self {
+            TypeName::Path(path) => Some(path),
+            _ => None,
+        }
+    }
+
+    /// Returns the crate of a path, eg `"alloc"` for `alloc::vec::Vec<u8>`.
+    ///
+    /// Primitives like `u8` have no crate.
+    pub fn crate_name(&self) -> Option<&str> {
+        match self {
+            TypeName::Path(path) => path.crate_name(),
+            TypeName::Closure { parent, .. } => parent.segments.first().map(|s| &s.name[..]),
+            _ => None,
+        }
+    }
+
+    /// Returns everything but the last segment of a path, eg `"alloc::vec"` for
+    /// `alloc::vec::Vec<u8>`.
+    pub fn module_path(&self) -> Option<String> {
+        match self {
+            TypeName::Path(path) => path.module_path(),
+            TypeName::Closure { parent, .. } => Some(parent.to_string()),
+            _ => None,
+        }
+    }
+
+    /// Returns the last segment of a path without its generics, eg `"Vec"` for
+    /// `alloc::vec::Vec<u8>`.
+    pub fn base_name(&self) -> Option<&str> {
+        match self {
+            TypeName::Path(path) => path.base_name(),
+            TypeName::Closure { marker, .. } => Some(marker),
+            _ => None,
+        }
+    }
+
+    /// Returns the angle-bracketed generics of a path, eg `[u8]` for `alloc::vec::Vec<u8>`.
+    pub fn generic_args(&self) -> &[GenericArg] {
+        match self {
+            TypeName::Path(path) => path.generic_args(),
+            _ => &[],
+        }
+    }
+
+    /// Calls `f` on every path in the type, outermost first.
+    pub(crate) fn for_each_path_mut(&mut self, f: &mut impl FnMut(&mut TypePath)) {
+        match self {
+            TypeName::Path(path) => path.for_each_path_mut(f),
+            TypeName::Qualified { self_ty, trait_, segments } => {
+                self_ty.for_each_path_mut(f);
+                if let Some(trait_) = trait_ {
+                    trait_.for_each_path_mut(f);
+                }
+                for segment in segments {
+                    segment.args.for_each_path_mut(f);
+                }
+            }
+            TypeName::Ref { ty, .. }
+            | TypeName::Ptr { ty, .. }
+            | TypeName::Array { ty, .. }
+            | TypeName::Slice(ty) => ty.for_each_path_mut(f),
+            TypeName::Tuple(tys) => {
+                for ty in tys {
+                    ty.for_each_path_mut(f);
+                }
+            }
+            TypeName::Fn(sig) => {
+                for ty in &mut sig.inputs {
+                    ty.for_each_path_mut(f);
+                }
+                if let Some(ty) = &mut sig.output {
+                    ty.for_each_path_mut(f);
+                }
+            }
+            TypeName::Dyn(bounds) | TypeName::Impl(bounds) => {
+                for bound in bounds {
+                    if let Bound::Trait { path, .. } = bound {
+                        path.for_each_path_mut(f);
+                    }
+                }
+            }
+            TypeName::Closure { parent, .. } => parent.for_each_path_mut(f),
+            TypeName::Never | TypeName::Infer => (),
+        }
+    }
+}
+
+impl TypePath {
+    /// Returns the first segment, if there are others after it.
+    pub fn crate_name(&self) -> Option<&str> {
+        match &self.segments[..] {
+            [first, _, ..] => Some(&first.name),
+            _ => None,
+        }
+    }
+
+    /// Returns every segment but the last, joined by `::`.
+    pub fn module_path(&self) -> Option<String> {
+        let (_, module) = self.segments.split_last()?;
+        if module.is_empty() {
+            return None;
+        }
+        Some(TypePath { segments: module.to_vec() }.to_string())
+    }
+
+    /// Returns the name of the last segment.
+    pub fn base_name(&self) -> Option<&str> {
+        self.segments.last().map(|s| &s.name[..])
+    }
+
+    /// Returns the angle-bracketed generics of the last segment.
+    pub fn generic_args(&self) -> &[GenericArg] {
+        match self.segments.last() {
+            Some(PathSegment { args: GenericArgs::AngleBracketed(args), .. }) => args,
+            _ => &[],
+        }
+    }
+
+    fn for_each_path_mut(&mut self, f: &mut impl FnMut(&mut TypePath)) {
+        f(self);
+        for segment in &mut self.segments {
+            segment.args.for_each_path_mut(f);
+        }
+    }
+}
+
+impl GenericArgs {
+    fn for_each_path_mut(&mut self, f: &mut impl FnMut(&mut TypePath)) {
+        match self {
+            GenericArgs::None => (),
+            GenericArgs::AngleBracketed(args) => {
+                for arg in args {
+                    match arg {
+                        GenericArg::Type(ty) | GenericArg::Binding { ty, .. } => ty.for_each_path_mut(f),
+                        GenericArg::Lifetime(_) | GenericArg::Const(_) => (),
+                    }
+                }
+            }
+            GenericArgs::Parenthesized { inputs, output } => {
+                for ty in inputs {
+                    ty.for_each_path_mut(f);
+                }
+                if let Some(ty) = output {
+                    ty.for_each_path_mut(f);
+                }
+            }
+        }
+    }
+}
+
+impl TypeName {
+    /// Calls `f` on this type and then on every type inside it, outermost first.
+    pub(crate) fn for_each_type_mut(&mut self, f: &mut impl FnMut(&mut TypeName)) {
+        f(self);
+        for child in self.children_mut() {
+            child.for_each_type_mut(f);
+        }
+    }
+
+    /// The types directly inside this one, eg `u8` & `alloc::string::String` for
+    /// `HashMap<u8, alloc::string::String>`.
+    pub(crate) fn children(&self) -> Vec<&TypeName> {
+        let mut children = vec![];
+        match self {
+            TypeName::Path(path) | TypeName::Closure { parent: path, .. } => path.children(&mut children),
+            TypeName::Qualified { self_ty, trait_, segments } => {
+                children.push(&**self_ty);
+                if let Some(trait_) = trait_ {
+                    trait_.children(&mut children);
+                }
+                for segment in segments {
+                    segment.args.children(&mut children);
+                }
+            }
+            TypeName::Ref { ty, .. }
+            | TypeName::Ptr { ty, .. }
+            | TypeName::Array { ty, .. }
+            | TypeName::Slice(ty) => children.push(&**ty),
+            TypeName::Tuple(tys) => children.extend(tys),
+            TypeName::Fn(sig) => children.extend(sig.inputs.iter().chain(sig.output.as_deref())),
+            TypeName::Dyn(bounds) | TypeName::Impl(bounds) => {
+                for bound in bounds {
+                    if let Bound::Trait { path, .. } = bound {
+                        path.children(&mut children);
+                    }
+                }
+            }
+            TypeName::Never | TypeName::Infer => (),
+        }
+        children
+    }
+
+    /// Like [`children`](Self::children), but mutable.
+    pub(crate) fn children_mut(&mut self) -> Vec<&mut TypeName> {
+        let mut children = vec![];
+        match self {
+            TypeName::Path(path) | TypeName::Closure { parent: path, .. } => path.children_mut(&mut children),
+            TypeName::Qualified { self_ty, trait_, segments } => {
+                children.push(&mut **self_ty);
+                if let Some(trait_) = trait_ {
+                    trait_.children_mut(&mut children);
+                }
+                for segment in segments {
+                    segment.args.children_mut(&mut children);
+                }
+            }
+            TypeName::Ref { ty, .. }
+            | TypeName::Ptr { ty, .. }
+            | TypeName::Array { ty, .. }
+            | TypeName::Slice(ty) => children.push(&mut **ty),
+            TypeName::Tuple(tys) => children.extend(tys),
+            TypeName::Fn(sig) => children.extend(sig.inputs.iter_mut().chain(sig.output.as_deref_mut())),
+            TypeName::Dyn(bounds) | TypeName::Impl(bounds) => {
+                for bound in bounds {
+                    if let Bound::Trait { path, .. } = bound {
+                        path.children_mut(&mut children);
+                    }
+                }
+            }
+            TypeName::Never | TypeName::Infer => (),
+        }
+        children
+    }
+
+    /// Are the two types the same, apart from their [`children`](Self::children)?
+    pub(crate) fn same_shape(&self, other: &TypeName) -> bool {
+        let skeleton = |ty: &TypeName| {
+            let mut ty = ty.clone();
+            for child in ty.children_mut() {
+                *child = TypeName::Infer;
+            }
+            ty
+        };
+        skeleton(self) == skeleton(other)
+    }
+}
+
+impl TypePath {
+    fn children<'a>(&'a self, children: &mut Vec<&'a TypeName>) {
+        for segment in &self.segments {
+            segment.args.children(children);
+        }
+    }
+
+    fn children_mut<'a>(&'a mut self, children: &mut Vec<&'a mut TypeName>) {
+        for segment in &mut self.segments {
+            segment.args.children_mut(children);
+        }
+    }
+}
+
+impl GenericArgs {
+    fn children<'a>(&'a self, children: &mut Vec<&'a TypeName>) {
+        match self {
+            GenericArgs::None => (),
+            GenericArgs::AngleBracketed(args) => {
+                for arg in args {
+                    match arg {
+                        GenericArg::Type(ty) | GenericArg::Binding { ty, .. } => children.push(ty),
+                        GenericArg::Lifetime(_) | GenericArg::Const(_) => (),
+                    }
+                }
+            }
+            GenericArgs::Parenthesized { inputs, output } => children.extend(inputs.iter().chain(output.as_deref())),
+        }
+    }
+
+    fn children_mut<'a>(&'a mut self, children: &mut Vec<&'a mut TypeName>) {
+        match self {
+            GenericArgs::None => (),
+            GenericArgs::AngleBracketed(args) => {
+                for arg in args {
+                    match arg {
+                        GenericArg::Type(ty) | GenericArg::Binding { ty, .. } => children.push(ty),
+                        GenericArg::Lifetime(_) | GenericArg::Const(_) => (),
+                    }
+                }
+            }
+            GenericArgs::Parenthesized { inputs, output } => {
+                children.extend(inputs.iter_mut().chain(output.as_deref_mut()))
+            }
+        }
+    }
+}
+
+impl FromStr for TypeName {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        TypeName::parse(s)
+    }
+}
+
+/// Is `marker` the last path segment of a closure, async block, or coroutine?
+pub(crate) fn is_closure_marker(marker: &str) -> bool {
+    let inner = marker.trim_start_matches('{').trim_end_matches('}');
+    let kind = inner.split('#').next().unwrap_or(inner);
+    matches!(
+        kind,
+        "closure" | "async_block" | "async_fn" | "async_closure" | "coroutine" | "gen_block" | "async_gen_block",
+    )
+}
+
+fn is_ident_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+pub(crate) fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Types nested deeper than this are rejected, rather than overflowing the stack.
+const MAX_DEPTH: usize = 100;
+
+struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+    /// How many types are being parsed, to stop runaway recursion.
+    depth: usize,
+}
+impl<'a> Parser<'a> {
+    fn error(&self, msg: &'static str) -> ParseError {
+        ParseError { pos: self.pos, msg }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.src[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn skip_ws(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    /// Skips whitespace, then consumes `s` if it's next.
+    fn eat(&mut self, s: &str) -> bool {
+        self.skip_ws();
+        if self.rest().starts_with(s) {
+            self.pos += s.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Like [`eat`](Self::eat), but `kw` must not be followed by more identifier characters.
+    fn eat_keyword(&mut self, kw: &str) -> bool {
+        self.skip_ws();
+        let rest = self.rest();
+        if rest.starts_with(kw) && !rest[kw.len()..].starts_with(is_ident_char) {
+            self.pos += kw.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, s: &str, msg: &'static str) -> Result<(), ParseError> {
+        if self.eat(s) {
+            Ok(())
+        } else {
+            Err(self.error(msg))
+        }
+    }
+
+    /// Consumes characters while `f` holds.
+    fn take_while(&mut self, f: impl Fn(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let len = rest.find(|c| !f(c)).unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    fn ident(&mut self) -> Option<&'a str> {
+        self.skip_ws();
+        let rest = self.rest();
+        let start = if rest.starts_with("r#") { 2 } else { 0 };
+        if !rest[start..].starts_with(is_ident_start) {
+            return None;
+        }
+        let len = rest[start..].find(|c| !is_ident_char(c)).map_or(rest.len(), |n| n + start);
+        self.pos += len;
+        Some(&rest[..len])
+    }
+
+    /// Consumes a `{...}` group with balanced braces.
+    fn braced(&mut self) -> Result<&'a str, ParseError> {
+        self.closing(&['{'], &['}'])
+    }
+
+    /// Consumes text until the bracket opened by the next character is closed.
+    fn closing(&mut self, open: &[char], close: &[char]) -> Result<&'a str, ParseError> {
+        let rest = self.rest();
+        let mut depth = 0usize;
+        for (i, c) in rest.char_indices() {
+            if open.contains(&c) {
+                depth += 1;
+            } else if close.contains(&c) {
+                depth = depth.checked_sub(1).ok_or_else(|| self.error("unbalanced brackets"))?;
+                if depth == 0 {
+                    self.pos += i + c.len_utf8();
+                    return Ok(&rest[..i + c.len_utf8()]);
+                }
+            }
+        }
+        Err(self.error("unclosed bracket"))
+    }
+
+    fn lifetime(&mut self) -> Result<String, ParseError> {
+        self.expect("'", "expected a lifetime")?;
+        let name = self.ident().ok_or_else(|| self.error("expected a lifetime"))?;
+        Ok(format!("'{name}"))
+    }
+
+    /// Parses a `for<'a, 'b>` binder, if there is one.
+    fn for_lifetimes(&mut self) -> Result<Vec<String>, ParseError> {
+        let mut lifetimes = vec![];
+        let start = self.pos;
+        if self.eat_keyword("for") {
+            if !self.eat("<") {
+                self.pos = start;
+                return Ok(lifetimes);
+            }
+            while !self.eat(">") {
+                lifetimes.push(self.lifetime()?);
+                if !self.eat(",") {
+                    self.expect(">", "expected `>`")?;
+                    break;
+                }
+            }
+        }
+        Ok(lifetimes)
+    }
+
+    fn ty(&mut self) -> Result<TypeName, ParseError> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error("types nested too deeply"));
+        }
+        self.depth += 1;
+        let ty = self.nested_ty();
+        self.depth -= 1;
+        ty
+    }
+
+    fn nested_ty(&mut self) -> Result<TypeName, ParseError> {
+        self.skip_ws();
+        if self.eat("&") {
+            let lifetime = if self.rest().starts_with('\'') {
+                Some(self.lifetime()?)
+            } else {
+                None
+            };
+            let mutable = self.eat_keyword("mut");
+            let ty = Box::new(self.ty()?);
+            return Ok(TypeName::Ref { lifetime, mutable, ty });
+        }
+        if self.eat("*") {
+            let mutable = if self.eat_keyword("mut") {
+                true
+            } else if self.eat_keyword("const") {
+                false
+            } else {
+                return Err(self.error("expected `const` or `mut`"));
+            };
+            let ty = Box::new(self.ty()?);
+            return Ok(TypeName::Ptr { mutable, ty });
+        }
+        if self.eat("(") {
+            let mut tys = vec![];
+            let mut trailing_comma = false;
+            while !self.eat(")") {
+                tys.push(self.ty()?);
+                trailing_comma = self.eat(",");
+                if !trailing_comma {
+                    self.expect(")", "expected `)`")?;
+                    break;
+                }
+            }
+            if tys.len() == 1 && !trailing_comma {
+                // Just parentheses.
+                return Ok(tys.pop().unwrap());
+            }
+            return Ok(TypeName::Tuple(tys));
+        }
+        if self.eat("[") {
+            let ty = Box::new(self.ty()?);
+            if self.eat(";") {
+                self.skip_ws();
+                let start = self.pos;
+                let mut depth = 0usize;
+                for (i, c) in self.rest().char_indices() {
+                    match c {
+                        '[' | '{' | '(' => depth += 1,
+                        ']' if depth == 0 => {
+                            let len = self.src[start..start + i].trim_end().to_owned();
+                            self.pos = start + i + 1;
+                            return Ok(TypeName::Array { ty, len });
+                        }
+                        ']' | '}' | ')' => depth = depth.saturating_sub(1),
+                        _ => (),
+                    }
+                }
+                return Err(self.error("expected `]`"));
+            }
+            self.expect("]", "expected `]`")?;
+            return Ok(TypeName::Slice(ty));
+        }
+        if self.eat("!") {
+            return Ok(TypeName::Never);
+        }
+        if self.eat_keyword("_") {
+            return Ok(TypeName::Infer);
+        }
+        if self.eat("<") {
+            let self_ty = Box::new(self.ty()?);
+            let trait_ = if self.eat_keyword("as") {
+                Some(self.path()?)
+            } else {
+                None
+            };
+            self.expect(">", "expected `>`")?;
+            let mut segments = vec![];
+            while self.eat("::") {
+                segments.push(self.segment()?);
+            }
+            if segments.is_empty() {
+                return Err(self.error("expected `::`"));
+            }
+            return Ok(TypeName::Qualified { self_ty, trait_, segments });
+        }
+        if self.eat_keyword("dyn") {
+            return Ok(TypeName::Dyn(self.bounds()?));
+        }
+        if self.eat_keyword("impl") {
+            return Ok(TypeName::Impl(self.bounds()?));
+        }
+        let start = self.pos;
+        let for_lifetimes = self.for_lifetimes()?;
+        let is_unsafe = self.eat_keyword("unsafe");
+        let abi = if self.eat_keyword("extern") {
+            self.skip_ws();
+            if self.rest().starts_with('"') {
+                self.pos += 1;
+                let abi = self.take_while(|c| c != '"');
+                self.expect("\"", "expected `\"`")?;
+                Some(abi.to_owned())
+            } else {
+                Some(String::new())
+            }
+        } else {
+            None
+        };
+        if self.eat_keyword("fn") {
+            self.expect("(", "expected `(`")?;
+            let mut inputs = vec![];
+            let mut variadic = false;
+            while !self.eat(")") {
+                if self.eat("...") {
+                    variadic = true;
+                } else {
+                    inputs.push(self.ty()?);
+                }
+                if !self.eat(",") {
+                    self.expect(")", "expected `)`")?;
+                    break;
+                }
+            }
+            let output = self.output()?;
+            return Ok(TypeName::Fn(FnSig { for_lifetimes, is_unsafe, abi, inputs, variadic, output }));
+        }
+        if self.pos != start {
+            return Err(self.error("expected `fn`"));
+        }
+        let mut path = self.path()?;
+        if path.segments.len() > 1 && path.base_name().is_some_and(is_closure_marker) {
+            let marker = path.segments.pop().unwrap().name;
+            return Ok(TypeName::Closure { parent: path, marker });
+        }
+        Ok(TypeName::Path(path))
+    }
+
+    /// Parses the `-> T` of a function, if there is one.
+    fn output(&mut self) -> Result<Option<Box<TypeName>>, ParseError> {
+        Ok(if self.eat("->") {
+            Some(Box::new(self.ty()?))
+        } else {
+            None
+        })
+    }
+
+    fn bounds(&mut self) -> Result<Vec<Bound>, ParseError> {
+        let mut bounds = vec![];
+        loop {
+            self.skip_ws();
+            if self.rest().starts_with('\'') {
+                bounds.push(Bound::Lifetime(self.lifetime()?));
+            } else {
+                let for_lifetimes = self.for_lifetimes()?;
+                let path = self.path()?;
+                bounds.push(Bound::Trait { for_lifetimes, path });
+            }
+            if !self.eat("+") {
+                return Ok(bounds);
+            }
+        }
+    }
+
+    fn path(&mut self) -> Result<TypePath, ParseError> {
+        let mut segments = vec![self.segment()?];
+        loop {
+            let start = self.pos;
+            if !self.eat("::") {
+                break;
+            }
+            self.skip_ws();
+            if self.rest().starts_with('<') {
+                // A turbofish belongs to the previous segment.
+                self.pos = start;
+                break;
+            }
+            segments.push(self.segment()?);
+        }
+        Ok(TypePath { segments })
+    }
+
+    fn segment(&mut self) -> Result<PathSegment, ParseError> {
+        self.skip_ws();
+        if self.rest().starts_with('{') {
+            let name = self.braced()?.to_owned();
+            return Ok(PathSegment { name, args: GenericArgs::None });
+        }
+        let name = self.ident().ok_or_else(|| self.error("expected a path"))?.to_owned();
+        let args = if self.rest().starts_with('(') {
+            self.pos += 1;
+            let mut inputs = vec![];
+            while !self.eat(")") {
+                inputs.push(self.ty()?);
+                if !self.eat(",") {
+                    self.expect(")", "expected `)`")?;
+                    break;
+                }
+            }
+            let output = self.output()?;
+            GenericArgs::Parenthesized { inputs, output }
+        } else if self.eat("<") || self.eat("::<") {
+            let mut args = vec![];
+            while !self.eat(">") {
+                args.push(self.generic_arg()?);
+                if !self.eat(",") {
+                    self.expect(">", "expected `>`")?;
+                    break;
+                }
+            }
+            GenericArgs::AngleBracketed(args)
+        } else {
+            GenericArgs::None
+        };
+        Ok(PathSegment { name, args })
+    }
+
+    fn generic_arg(&mut self) -> Result<GenericArg, ParseError> {
+        self.skip_ws();
+        let rest = self.rest();
+        if rest.starts_with('\'') {
+            // A lifetime, or a char literal.
+            let start = self.pos;
+            self.pos += 1;
+            if self.rest().starts_with('\\') {
+                self.pos += 1;
+            }
+            self.pos += self.peek().map_or(0, char::len_utf8);
+            self.take_while(is_ident_char);
+            if self.rest().starts_with('\'') {
+                self.pos += 1;
+                return Ok(GenericArg::Const(self.src[start..self.pos].to_owned()));
+            }
+            self.pos = start;
+            return Ok(GenericArg::Lifetime(self.lifetime()?));
+        }
+        if rest.starts_with(|c: char| c.is_ascii_digit() || c == '-') {
+            let start = self.pos;
+            self.pos += 1;
+            self.take_while(|c| is_ident_char(c) || c == '.');
+            return Ok(GenericArg::Const(self.src[start..self.pos].to_owned()));
+        }
+        if rest.starts_with('{') {
+            let start = self.pos;
+            let braced = self.braced()?;
+            // Unless it's the first segment of a path.
+            if !self.rest().starts_with("::") {
+                return Ok(GenericArg::Const(braced.to_owned()));
+            }
+            self.pos = start;
+        }
+        if rest.starts_with('"') {
+            let start = self.pos;
+            self.pos += 1;
+            let mut escaped = false;
+            for (i, c) in self.rest().char_indices() {
+                match c {
+                    '"' if !escaped => {
+                        self.pos += i + 1;
+                        return Ok(GenericArg::Const(self.src[start..self.pos].to_owned()));
+                    }
+                    '\\' => escaped = !escaped,
+                    _ => escaped = false,
+                }
+            }
+            return Err(self.error("unclosed string"));
+        }
+        if self.eat_keyword("true") {
+            return Ok(GenericArg::Const("true".into()));
+        }
+        if self.eat_keyword("false") {
+            return Ok(GenericArg::Const("false".into()));
+        }
+        let start = self.pos;
+        if let Some(name) = self.ident() {
+            if self.eat("=") && !self.rest().starts_with('=') {
+                let ty = self.ty()?;
+                return Ok(GenericArg::Binding { name: name.to_owned(), ty });
+            }
+            self.pos = start;
+        }
+        Ok(GenericArg::Type(self.ty()?))
+    }
+}
+
+// The `Display` impls pass the formatter down, so that `{:#}` reaches every closure.
+
+/// Writes `items` separated by `sep`.
+fn list<T: fmt::Display>(f: &mut fmt::Formatter, items: &[T], sep: &str) -> fmt::Result {
+    for (i, item) in items.iter().enumerate() {
+        if i != 0 {
+            f.write_str(sep)?;
+        }
+        fmt::Display::fmt(item, f)?;
+    }
+    Ok(())
+}
+
+fn for_lifetimes(f: &mut fmt::Formatter, lifetimes: &[String]) -> fmt::Result {
+    if !lifetimes.is_empty() {
+        write!(f, "for<{}> ", lifetimes.join(", "))?;
+    }
+    Ok(())
+}
+
+/// Describes a closure for people, eg `closure in main`.
+fn describe_closure(f: &mut fmt::Formatter, parent: &[PathSegment], marker: &str) -> fmt::Result {
+    let inner = marker.trim_start_matches('{').trim_end_matches('}');
+    let kind = inner.split('#').next().unwrap_or(inner).replace('_', " ");
+    match parent.split_last() {
+        Some((last, parent)) if is_closure_marker(&last.name) && !parent.is_empty() => {
+            write!(f, "{kind} in ")?;
+            describe_closure(f, parent, &last.name)
+        }
+        Some((last, _)) => {
+            if kind == "async fn" {
+                write!(f, "{kind} ")?;
+            } else {
+                write!(f, "{kind} in ")?;
+            }
+            fmt::Display::fmt(last, f)
+        }
+        None => f.write_str(&kind),
+    }
+}
+
+/// `{:#}` describes closures for people, eg `closure in main` rather than
+/// `my_crate::main::{{closure}}`. That form can't be parsed back.
+impl fmt::Display for TypeName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TypeName::Path(path) => fmt::Display::fmt(path, f),
+            TypeName::Qualified { self_ty, trait_, segments } => {
+                f.write_str("<")?;
+                fmt::Display::fmt(self_ty, f)?;
+                if let Some(trait_) = trait_ {
+                    f.write_str(" as ")?;
+                    fmt::Display::fmt(trait_, f)?;
+                }
+                f.write_str(">")?;
+                for segment in segments {
+                    f.write_str("::")?;
+                    fmt::Display::fmt(segment, f)?;
+                }
+                Ok(())
+            }
+            TypeName::Ref { lifetime, mutable, ty } => {
+                f.write_str("&")?;
+                if let Some(lifetime) = lifetime {
+                    write!(f, "{lifetime} ")?;
+                }
+                if *mutable {
+                    f.write_str("mut ")?;
+                }
+                fmt::Display::fmt(ty, f)
+            }
+            TypeName::Ptr { mutable, ty } => {
+                f.write_str(if *mutable { "*mut " } else { "*const " })?;
+                fmt::Display::fmt(ty, f)
+            }
+            TypeName::Tuple(tys) => {
+                f.write_str("(")?;
+                list(f, tys, ", ")?;
+                if tys.len() == 1 {
+                    f.write_str(",")?;
+                }
+                f.write_str(")")
+            }
+            TypeName::Array { ty, len } => {
+                f.write_str("[")?;
+                fmt::Display::fmt(ty, f)?;
+                write!(f, "; {len}]")
+            }
+            TypeName::Slice(ty) => {
+                f.write_str("[")?;
+                fmt::Display::fmt(ty, f)?;
+                f.write_str("]")
+            }
+            TypeName::Fn(sig) => fmt::Display::fmt(sig, f),
+            TypeName::Dyn(bounds) => {
+                f.write_str("dyn ")?;
+                list(f, bounds, " + ")
+            }
+            TypeName::Impl(bounds) => {
+                f.write_str("impl ")?;
+                list(f, bounds, " + ")
+            }
+            TypeName::Closure { parent, marker } => {
+                if f.alternate() {
+                    describe_closure(f, &parent.segments, marker)
+                } else {
+                    fmt::Display::fmt(parent, f)?;
+                    write!(f, "::{marker}")
+                }
+            }
+            TypeName::Never => f.write_str("!"),
+            TypeName::Infer => f.write_str("_"),
+        }
+    }
+}
+
+impl fmt::Display for TypePath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        list(f, &self.segments, "::")
+    }
+}
+
+impl fmt::Display for PathSegment {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.name)?;
+        fmt::Display::fmt(&self.args, f)
+    }
+}
+
+impl fmt::Display for GenericArgs {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GenericArgs::None => Ok(()),
+            GenericArgs::AngleBracketed(args) => {
+                f.write_str("<")?;
+                list(f, args, ", ")?;
+                f.write_str(">")
+            }
+            GenericArgs::Parenthesized { inputs, output } => {
+                f.write_str("(")?;
+                list(f, inputs, ", ")?;
+                f.write_str(")")?;
+                if let Some(output) = output {
+                    f.write_str(" -> ")?;
+                    fmt::Display::fmt(output, f)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl fmt::Display for GenericArg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GenericArg::Type(ty) => fmt::Display::fmt(ty, f),
+            GenericArg::Lifetime(s) | GenericArg::Const(s) => f.write_str(s),
+            GenericArg::Binding { name, ty } => {
+                write!(f, "{name} = ")?;
+                fmt::Display::fmt(ty, f)
+            }
+        }
+    }
+}
+
+impl fmt::Display for FnSig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for_lifetimes(f, &self.for_lifetimes)?;
+        if self.is_unsafe {
+            f.write_str("unsafe ")?;
+        }
+        match self.abi.as_deref() {
+            None => (),
+            Some("") => f.write_str("extern ")?,
+            Some(abi) => write!(f, "extern \"{abi}\" ")?,
+        }
+        f.write_str("fn(")?;
+        list(f, &self.inputs, ", ")?;
+        if self.variadic {
+            if !self.inputs.is_empty() {
+                f.write_str(", ")?;
+            }
+            f.write_str("...")?;
+        }
+        f.write_str(")")?;
+        if let Some(output) = &self.output {
+            f.write_str(" -> ")?;
+            fmt::Display::fmt(output, f)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Bound {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Bound::Trait { for_lifetimes: lifetimes, path } => {
+                for_lifetimes(f, lifetimes)?;
+                fmt::Display::fmt(path, f)
+            }
+            Bound::Lifetime(lifetime) => f.write_str(lifetime),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[track_caller]
+    fn round_trip(name: &str) -> TypeName {
+        let parsed = TypeName::parse(name).unwrap_or_else(|e| panic!("{name:?}: {e}"));
+        assert_eq!(parsed.to_string(), name);
+        parsed
+    }
+
+    #[test]
+    fn round_trips() {
+        for name in [
+            "u8",
+            "()",
+            "(u8,)",
+            "(u8, (), (i32,))",
+            "&&mut u8",
+            "&'_ str",
+            "*mut *const u8",
+            "[char; 0]",
+            "&mut [u8]",
+            "!",
+            "_",
+            "my_crate::S<3>",
+            "my_crate::S<-3, true, 'x', '\\n'>",
+            "my_crate::S<{ N + 1 }>",
+            "alloc::borrow::Cow<'_, str>",
+            "std::collections::hash::map::HashMap<alloc::string::String, alloc::vec::Vec<u8>>",
+            "fn(&'_ u8, alloc::vec::Vec<alloc::string::String>) -> core::option::Option<alloc::string::String>",
+            "for<'a> fn(&'a u8) -> &'a u8",
+            "unsafe extern \"C\" fn(u8, ...)",
+            "extern fn()",
+            "dyn core::ops::function::Fn(u8) -> u8 + core::marker::Send",
+            "dyn core::iter::traits::iterator::Iterator<Item = u8> + core::marker::Send + core::marker::Sync",
+            "&dyn core::fmt::Debug + core::marker::Send",
+            "alloc::boxed::Box<dyn core::ops::function::Fn() -> alloc::boxed::Box<dyn core::ops::function::Fn()>>",
+            "dyn core::any::Any + 'static",
+            "impl core::future::future::Future<Output = ()>",
+            "<u8 as my_crate::Tr>::A",
+            "<u8>::A",
+            "my_crate::gen<u8>::{{closure}}",
+            "my_crate::main::{closure#0}::{closure#1}",
+            "my_crate::r#type",
+        ] {
+            round_trip(name);
+        }
+    }
+
+    #[test]
+    fn structure() {
+        let parsed = round_trip("&'_ mut [alloc::vec::Vec<u8>; 3]");
+        let TypeName::Ref { lifetime, mutable: true, ty } = parsed else { panic!() };
+        assert_eq!(lifetime.as_deref(), Some("'_"));
+        let TypeName::Array { ty, len } = *ty else { panic!() };
+        assert_eq!(len, "3");
+        assert_eq!(ty.crate_name(), Some("alloc"));
+        assert_eq!(ty.module_path().as_deref(), Some("alloc::vec"));
+        assert_eq!(ty.base_name(), Some("Vec"));
+        assert_eq!(ty.generic_args(), [GenericArg::Type(TypeName::parse("u8").unwrap())]);
+    }
+
+    #[test]
+    fn closures() {
+        let parsed = round_trip("my_crate::gen<u8>::{{closure}}");
+        let TypeName::Closure { parent, marker } = &parsed else { panic!() };
+        assert_eq!(marker, "{{closure}}");
+        assert_eq!(parent.to_string(), "my_crate::gen<u8>");
+        assert_eq!(parsed.crate_name(), Some("my_crate"));
+        // A struct defined inside a closure is just a path.
+        assert!(matches!(round_trip("my_crate::main::{{closure}}::Local"), TypeName::Path(_)));
+    }
+
+    #[test]
+    fn describe_closures() {
+        let describe = |name| format!("{:#}", TypeName::parse(name).unwrap());
+        assert_eq!(describe("my_crate::main::{{closure}}"), "closure in main");
+        assert_eq!(describe("my_crate::gen<u8>::{{closure}}"), "closure in gen<u8>");
+        assert_eq!(describe("my_crate::main::{{closure}}::{{closure}}"), "closure in closure in main");
+        assert_eq!(describe("my_crate::main::{async_block#0}"), "async block in main");
+        assert_eq!(describe("my_crate::run::{async_fn#0}"), "async fn run");
+        assert_eq!(
+            describe("alloc::boxed::Box<dyn core::ops::function::Fn(my_crate::S::new::{closure#1})>"),
+            "alloc::boxed::Box<dyn core::ops::function::Fn(closure in new)>",
+        );
+    }
+
+    #[test]
+    fn primitives() {
+        let parsed = round_trip("u8");
+        assert_eq!(parsed.crate_name(), None);
+        assert_eq!(parsed.module_path(), None);
+        assert_eq!(parsed.base_name(), Some("u8"));
+    }
+
+    #[test]
+    fn whitespace() {
+        let parsed = TypeName::parse(" Vec < u8 , ( ) > ").unwrap();
+        assert_eq!(parsed.to_string(), "Vec<u8, ()>");
+        assert_eq!(TypeName::parse("(u8)").unwrap().to_string(), "u8");
+    }
+
+    #[test]
+    fn errors() {
+        for name in ["", "Vec<u8", "Vec<u8>>", "*u8", "[u8; 3", "fn(", "a::", "&'", "<u8>"] {
+            assert!(TypeName::parse(name).is_err(), "{name:?}");
+        }
+        let deep = |n| format!("{}u8{}", "Vec<".repeat(n), ">".repeat(n));
+        assert!(TypeName::parse(&deep(super::MAX_DEPTH - 1)).is_ok());
+        assert_eq!(
+            TypeName::parse(&deep(100_000)).unwrap_err().to_string(),
+            format!("types nested too deeply at byte {}", 4 * super::MAX_DEPTH),
+        );
+        assert!(TypeName::parse(&"&".repeat(100_000)).is_err());
+    }
+}
@@ -0,0 +1,370 @@
+use std::fmt;
+use std::str::FromStr;
+use crate::parse::{is_ident_char, Bound, GenericArg, GenericArgs, ParseError, PathSegment, TypeName, TypePath};
+use crate::rules::PrettyRules;
+use crate::style::sort_bounds;
+use crate::Ty;
+
+/// A glob-style pattern that type names are matched against structurally, eg `Vec<*>`.
+///
+/// Patterns are written like type names, with some wildcards:
+/// * `_` matches any one type, or a const argument.
+/// * `*` matches any number of generic arguments, tuple elements or function inputs, so `(*)`
+///   is any tuple.
+/// * `**::` at the start of a path matches any module path, or none.
+///
+/// Other paths are written in full, as they are [canonically](crate::canonical), or
+/// [prettily](crate::pretty): `Vec`, `std::vec::Vec` & `alloc::vec::Vec` all match a `Vec`,
+/// but `vec::Vec` & `Error` don't. Lifetimes are ignored, and so is the order of the bounds of
+/// trait objects.
+/// ```
+/// # use ezty::{Ty, TyPattern};
+/// # use std::collections::HashMap;
+/// let pattern: TyPattern = "Vec<*>".parse().unwrap();
+/// assert!(pattern.matches(&Ty::of::<Vec<u8>>()));
+/// assert!(!pattern.matches(&Ty::of::<Option<Vec<u8>>>()));
+///
+/// let pattern = TyPattern::new("HashMap<String, _>").unwrap();
+/// assert!(pattern.matches(&Ty::of::<HashMap<String, Vec<u8>>>()));
+/// assert!(!pattern.matches(&Ty::of::<HashMap<u8, u8>>()));
+///
+/// let pattern = TyPattern::new("Result<_, **::Error>").unwrap();
+/// assert!(pattern.matches(&Ty::of::<Result<u8, std::io::Error>>()));
+/// assert!(pattern.matches(&Ty::of::<Result<(), std::fmt::Error>>()));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TyPattern {
+    src: String,
+    pattern: TypeName,
+}
+
+/// How wildcards are written once they've been made parseable. `*` is an argument list, and
+/// `**` a path prefix. A `*` alone in parentheses is a tuple, not a type in parentheses.
+const ANY_ARGS: &str = "{*}";
+const ANY_TUPLE: &str = "{*},";
+const ANY_PREFIX: &str = "{**}";
+
+impl TyPattern {
+    pub fn new(pattern: &str) -> Result<TyPattern, ParseError> {
+        // Wildcards aren't valid type names, so they're wrapped in braces, like the `{{closure}}`
+        // segments of closures.
+        let mut src = String::with_capacity(pattern.len() + 8);
+        // The positions in `src` where the braces of wildcards were added.
+        let mut added = vec![];
+        let mut rest = pattern;
+        while let Some(c) = rest.chars().next() {
+            let after = |n: usize| rest[n..].trim_start();
+            let wildcard = if rest.starts_with("**") && after(2).starts_with("::") {
+                Some(("**", ANY_PREFIX))
+            } else if c == '*' && (after(1).is_empty() || after(1).starts_with([',', '>', ')'])) {
+                // Unlike those of `fn(*)` or `Fn(*)`.
+                let tuple = after(1).starts_with(')')
+                    && src.trim_end().strip_suffix('(').is_some_and(|before| !before.trim_end().ends_with(is_ident_char));
+                Some(("*", if tuple { ANY_TUPLE } else { ANY_ARGS }))
+            } else {
+                None
+            };
+            if let Some((from, to)) = wildcard {
+                added.extend((0..to.len()).filter(|&i| i == 0 || i > from.len()).map(|i| src.len() + i));
+                src.push_str(to);
+                rest = &rest[from.len()..];
+            } else {
+                src.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+        let pattern_name = TypeName::parse(&src).map_err(|mut e| {
+            e.pos -= added.iter().filter(|&&added| added < e.pos).count();
+            e
+        })?;
+        Ok(TyPattern { src: pattern.to_owned(), pattern: pattern_name })
+    }
+
+    /// Does the name of `ty` match?
+    pub fn matches(&self, ty: &Ty) -> bool {
+        ty.parse().is_ok_and(|name| self.matches_name(&name))
+    }
+
+    /// Does a parsed [`type_name()`](std::any::type_name) match?
+    pub fn matches_name(&self, name: &TypeName) -> bool {
+        PrettyRules::with_current(|rules| Matcher { rules }.ty(&self.pattern, name))
+    }
+
+    /// The pattern as it was written.
+    pub fn as_str(&self) -> &str {
+        &self.src
+    }
+}
+
+impl FromStr for TyPattern {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        TyPattern::new(s)
+    }
+}
+
+impl fmt::Display for TyPattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.src)
+    }
+}
+
+struct Matcher<'a> {
+    /// The rules in effect, for the pretty forms of paths.
+    rules: &'a PrettyRules,
+}
+
+impl Matcher<'_> {
+    fn ty(&self, pattern: &TypeName, ty: &TypeName) -> bool {
+        if *pattern == TypeName::Infer || is_any_args(pattern) {
+            return true;
+        }
+        if let (Some(pattern), Some(path)) = (as_path(pattern), as_path(ty)) {
+            return self.path(&pattern, &path);
+        }
+        match (pattern, ty) {
+            (
+                TypeName::Qualified { self_ty: p_self, trait_: p_trait, segments: p_segments },
+                TypeName::Qualified { self_ty, trait_, segments },
+            ) => {
+                self.ty(p_self, self_ty)
+                    && match (p_trait, trait_) {
+                        (Some(p_trait), Some(trait_)) => self.path(p_trait, trait_),
+                        (p_trait, trait_) => p_trait.is_none() && trait_.is_none(),
+                    }
+                    && p_segments.len() == segments.len()
+                    && p_segments.iter().zip(segments).all(|(p, s)| p.name == s.name && self.args(&p.args, &s.args))
+            }
+            (TypeName::Ref { mutable: p_mut, ty: p_ty, .. }, TypeName::Ref { mutable, ty, .. })
+            | (TypeName::Ptr { mutable: p_mut, ty: p_ty }, TypeName::Ptr { mutable, ty }) => {
+                p_mut == mutable && self.ty(p_ty, ty)
+            }
+            (TypeName::Array { ty: p_ty, len: p_len }, TypeName::Array { ty, len }) => {
+                (p_len == "_" || p_len == len) && self.ty(p_ty, ty)
+            }
+            (TypeName::Slice(p_ty), TypeName::Slice(ty)) => self.ty(p_ty, ty),
+            (TypeName::Tuple(p_tys), TypeName::Tuple(tys)) => self.tys(p_tys, tys),
+            (TypeName::Fn(p_sig), TypeName::Fn(sig)) => {
+                (p_sig.is_unsafe, &p_sig.abi, p_sig.variadic) == (sig.is_unsafe, &sig.abi, sig.variadic)
+                    && self.tys(&p_sig.inputs, &sig.inputs)
+                    && self.output(p_sig.output.as_deref(), sig.output.as_deref())
+            }
+            (TypeName::Dyn(p_bounds), TypeName::Dyn(bounds)) | (TypeName::Impl(p_bounds), TypeName::Impl(bounds)) => {
+                // The order of auto traits doesn't matter, as when names are rendered.
+                let (mut p_bounds, mut bounds) = (p_bounds.clone(), bounds.clone());
+                sort_bounds(&mut p_bounds);
+                sort_bounds(&mut bounds);
+                p_bounds.len() == bounds.len()
+                    && p_bounds.iter().zip(&bounds).all(|(p, b)| match (p, b) {
+                        (Bound::Trait { path: p, .. }, Bound::Trait { path, .. }) => self.path(p, path),
+                        (Bound::Lifetime(_), Bound::Lifetime(_)) => true,
+                        _ => false,
+                    })
+            }
+            (TypeName::Never, TypeName::Never) => true,
+            _ => false,
+        }
+    }
+
+    /// Matches a list of types, where [`ANY_ARGS`] matches any number of them.
+    fn tys(&self, patterns: &[TypeName], tys: &[TypeName]) -> bool {
+        glob(patterns, tys, &is_any_args, &|p, t| self.ty(p, t))
+    }
+
+    fn output(&self, pattern: Option<&TypeName>, ty: Option<&TypeName>) -> bool {
+        match (pattern, ty) {
+            (Some(p), Some(t)) => self.ty(p, t),
+            (p, t) => p.is_none() && t.is_none(),
+        }
+    }
+
+    fn path(&self, pattern: &TypePath, path: &TypePath) -> bool {
+        let (Some(p_last), Some(last)) = (pattern.segments.last(), path.segments.last()) else { return false };
+        if !self.args(&p_last.args, &last.args) {
+            return false;
+        }
+        let names = |path: &TypePath| path.segments.iter().map(|s| s.name.clone()).collect::<Vec<_>>();
+        let mut plain = path.clone();
+        for segment in &mut plain.segments {
+            segment.args = GenericArgs::None;
+        }
+        let mut pretty = plain.clone();
+        let mut canonical = plain.clone();
+        let forms = [
+            Some(names(&plain)),
+            self.rules.apply(&mut pretty).then(|| names(&pretty)),
+            PrettyRules::canonical_ref().apply(&mut canonical).then(|| names(&canonical)),
+        ];
+        let p_names = names(pattern);
+        let (p_names, any_prefix) = match p_names.split_first() {
+            Some((first, rest)) if first == ANY_PREFIX => (rest, true),
+            _ => (&p_names[..], false),
+        };
+        forms.iter().flatten().any(|form| if any_prefix { form.ends_with(p_names) } else { form == p_names })
+    }
+
+    fn args(&self, pattern: &GenericArgs, args: &GenericArgs) -> bool {
+        match (pattern, args) {
+            (GenericArgs::None, GenericArgs::None) => true,
+            (GenericArgs::AngleBracketed(p_args), GenericArgs::AngleBracketed(args)) => {
+                let not_lifetime = |a: &&GenericArg| !matches!(a, GenericArg::Lifetime(_));
+                let p_args: Vec<_> = p_args.iter().filter(not_lifetime).collect();
+                let args: Vec<_> = args.iter().filter(not_lifetime).collect();
+                let is_any = |a: &&GenericArg| matches!(a, GenericArg::Const(c) if c == ANY_ARGS);
+                glob(&p_args, &args, &is_any, &|p, a| match (p, a) {
+                    (GenericArg::Type(TypeName::Infer), GenericArg::Const(_)) => true,
+                    (GenericArg::Type(p), GenericArg::Type(t)) => self.ty(p, t),
+                    (GenericArg::Const(p), GenericArg::Const(c)) => p == c,
+                    (GenericArg::Binding { name: p_name, ty: p }, GenericArg::Binding { name, ty }) => {
+                        p_name == name && self.ty(p, ty)
+                    }
+                    _ => false,
+                })
+            }
+            (
+                GenericArgs::Parenthesized { inputs: p_inputs, output: p_output },
+                GenericArgs::Parenthesized { inputs, output },
+            ) => self.tys(p_inputs, inputs) && self.output(p_output.as_deref(), output.as_deref()),
+            _ => false,
+        }
+    }
+}
+
+/// Paths & closures, as paths.
+fn as_path(ty: &TypeName) -> Option<TypePath> {
+    match ty {
+        TypeName::Path(path) => Some(path.clone()),
+        TypeName::Closure { parent, marker } => {
+            let mut path = parent.clone();
+            path.segments.push(PathSegment { name: marker.clone(), args: GenericArgs::None });
+            Some(path)
+        }
+        _ => None,
+    }
+}
+
+fn is_any_args(ty: &TypeName) -> bool {
+    matches!(ty, TypeName::Path(path) if path.segments.len() == 1 && path.segments[0].name == ANY_ARGS)
+}
+
+/// Matches `items` against `patterns` one by one, except that patterns that are `any` match any
+/// number of items.
+///
+/// Only the last `any` is ever backtracked to, as the other patterns each match one item, so this
+/// takes at most `patterns.len() * items.len()` steps.
+fn glob<P, T>(patterns: &[P], items: &[T], any: &dyn Fn(&P) -> bool, matches: &dyn Fn(&P, &T) -> bool) -> bool {
+    let (mut p, mut i) = (0, 0);
+    // The patterns after the last `any`, and the first item that it hasn't taken.
+    let mut resume = None;
+    while i < items.len() {
+        match patterns.get(p) {
+            Some(pattern) if any(pattern) => {
+                resume = Some((p + 1, i));
+                p += 1;
+            }
+            Some(pattern) if matches(pattern, &items[i]) => {
+                p += 1;
+                i += 1;
+            }
+            _ => {
+                // Let the last `any` take one more item.
+                let Some((after_any, taken)) = resume else { return false };
+                resume = Some((after_any, taken + 1));
+                (p, i) = (after_any, taken + 1);
+            }
+        }
+    }
+    patterns[p..].iter().all(any)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TyPattern;
+    use crate::parse::TypeName;
+    use crate::Ty;
+    use std::collections::HashMap;
+    use std::rc::Rc;
+
+    fn matches<T: ?Sized + 'static>(pattern: &str) -> bool {
+        TyPattern::new(pattern).unwrap().matches(&Ty::of::<T>())
+    }
+
+    #[test]
+    fn wildcards() {
+        assert!(matches::<Vec<u8>>("Vec<_>"));
+        assert!(matches::<Vec<u8>>("Vec<*>"));
+        assert!(!matches::<Vec<u8>>("Vec"));
+        assert!(!matches::<Vec<u8>>("Vec<_, _>"));
+        assert!(matches::<HashMap<String, u8>>("HashMap<*, u8>"));
+        assert!(matches::<HashMap<String, u8>>("HashMap<String, *>"));
+        assert!(!matches::<HashMap<String, u8>>("HashMap<u8, *>"));
+        assert!(matches::<(u8, String, bool)>("(u8, *)"));
+        assert!(matches::<(u8, String, bool)>("(*, bool)"));
+        assert!(matches::<()>("(*)"));
+        assert!(matches::<(u8,)>("(*)"));
+        assert!(matches::<(u8, String)>("( * )"));
+        assert!(!matches::<u8>("(*)"));
+        assert!(!matches::<Vec<u8>>("Vec<(*)>"));
+        assert!(matches::<Vec<(u8, u8)>>("Vec<(*)>"));
+        assert!(matches::<&(u8, u8)>("&(*)"));
+        assert!(!matches::<(u8, String, bool)>("(*, u8)"));
+        assert!(matches::<fn(u8, u8) -> u8>("fn(*) -> u8"));
+        assert!(!matches::<fn(u8, u8) -> u8>("fn(*)"));
+        assert!(matches::<[u8; 4]>("[_; _]"));
+        assert!(matches::<[u8; 4]>("[u8; 4]"));
+        assert!(!matches::<[u8; 4]>("[u8; 5]"));
+        assert!(matches::<*const *mut u8>("*const *mut _"));
+        assert!(matches::<u8>("_"));
+        assert!(matches::<u8>("*"));
+    }
+
+    #[test]
+    fn many_wildcards() {
+        let tuple = format!("({})", vec!["u8"; 60].join(", "));
+        let name = TypeName::parse(&tuple).unwrap();
+        let pattern = |p: &str| TyPattern::new(p).unwrap().matches_name(&name);
+        assert!(pattern("(*, u8, *, u8, *, u8, *, u8, *, u8, *, u8, *, u8, *)"));
+        assert!(pattern("(*, u8, *, u8, *, u8, *, u8, *, u8, *, u8, *, u8)"));
+        assert!(!pattern("(*, u8, *, u8, *, u8, *, u8, *, u8, *, u8, *, u16, *)"));
+        assert!(!pattern("(*, u8, *, u8, *, u8, *, u8, *, u8, *, u8, *, u8, *, u16)"));
+        assert!(!pattern("(u16, *, u8, *, u8, *, u8, *, u8, *, u8, *, u8, *)"));
+        assert!(pattern("(u8, *, u8, *, *, u8)"));
+    }
+
+    #[test]
+    fn paths() {
+        assert!(matches::<Vec<u8>>("alloc::vec::Vec<u8>"));
+        assert!(matches::<Vec<u8>>("std::vec::Vec<u8>"));
+        assert!(!matches::<Vec<u8>>("vec::Vec<u8>"));
+        assert!(matches::<Vec<u8>>("**::Vec<u8>"));
+        assert!(matches::<Vec<u8>>("**::vec::Vec<u8>"));
+        assert!(matches::<std::io::Error>("io::Error"));
+        assert!(matches::<std::io::Error>("**::Error"));
+        assert!(!matches::<std::io::Error>("Error"));
+        assert!(!matches::<std::io::Error>("**::fmt::Error"));
+        assert!(matches::<Option<std::io::Error>>("Option<**::Error>"));
+        assert!(matches::<Result<u8, std::io::Error>>("Result<_, io::Error>"));
+        assert!(matches::<Rc<dyn std::error::Error + Send>>("Rc<dyn **::Error + Send>"));
+        assert!(matches::<&'static Option<Box<dyn Fn(u8) -> u8>>>("&Option<Box<dyn Fn(*) -> _>>"));
+        assert!(matches::<Box<dyn std::error::Error + Send + Sync>>("Box<dyn Sync + Send + **::Error>"));
+        assert!(matches::<Box<dyn std::error::Error + Send>>("Box<dyn Send + std::error::Error>"));
+        assert!(!matches::<Box<dyn std::error::Error + Send>>("Box<dyn Sync + std::error::Error>"));
+        assert!(!matches::<Box<dyn std::error::Error + Send>>("Box<dyn std::error::Error>"));
+        assert!(!matches::<&'static mut u8>("&u8"));
+        let closure = || ();
+        let name = Ty::of_every::<Vec<u8>>().parse().unwrap();
+        assert!(TyPattern::new("**::Vec<_>").unwrap().matches_name(&name));
+        fn ty_of<T: 'static>(_: &T) -> Ty { Ty::of::<T>() }
+        assert!(TyPattern::new("**::{{closure}}").unwrap().matches(&ty_of(&closure)));
+    }
+
+    #[test]
+    fn parse() {
+        let pattern: TyPattern = "Vec< * >".parse().unwrap();
+        assert_eq!(pattern.to_string(), "Vec< * >");
+        assert_eq!(pattern.as_str(), "Vec< * >");
+        assert_eq!(TyPattern::new("(*, **::X<").unwrap_err().pos, 10);
+        assert!(TyPattern::new("Vec<**>").is_err());
+        assert_eq!(TyPattern::new("((*), u8").unwrap_err().pos, 8);
+    }
+}
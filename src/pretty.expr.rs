@@ -1,18 +1,536 @@
 &[
     // This file is here so that you can easily patch it with cargo.
-    ("alloc::boxed::", "Box"),
-    ("alloc::collections::binary_heap::", "BinaryHeap"),
-    ("alloc::collections::btree::map::", "BTreeMap"),
-    ("alloc::collections::btree::set::", "BTreeSet"),
-    ("alloc::collections::linked_list::", "LinkedList"),
-    ("alloc::collections::vec_deque::", "VecDeque"),
-    ("alloc::sync::", "Arc"),
-    ("alloc::vec::", "Vec"),
-    ("core::cell::", "Cell"),
-    ("core::cell::", "RefCell"),
-    ("core::option::", "Option"),
-    ("core::result::", "Result"),
-    ("std::collections::hash::map::", "HashMap"),
-    ("std::collections::hash::set::", "HashSet"),
-    ("std::sync::rwlock::", "RwLock"),
+    //
+    // Each entry is (`type_name` path, pretty path). Every type in std, core & alloc that is
+    // stable as of Rust 1.95 is here, along with the paths that older compilers gave some of
+    // them, eg `std::sync::mutex::Mutex`. A pretty path is the type's name if that is unique among
+    // them, or else just enough of its public path to make it unique, eg `io::Error` & `fmt::Error`.
+    ("alloc::borrow::Cow", "Cow"),
+    ("alloc::borrow::ToOwned", "ToOwned"),
+    ("alloc::boxed::Box", "Box"),
+    ("alloc::collections::TryReserveError", "TryReserveError"),
+    ("alloc::collections::binary_heap::BinaryHeap", "BinaryHeap"),
+    ("alloc::collections::binary_heap::Drain", "binary_heap::Drain"),
+    ("alloc::collections::binary_heap::IntoIter", "binary_heap::IntoIter"),
+    ("alloc::collections::binary_heap::Iter", "binary_heap::Iter"),
+    ("alloc::collections::binary_heap::PeekMut", "PeekMut"),
+    ("alloc::collections::btree::map::BTreeMap", "BTreeMap"),
+    ("alloc::collections::btree::map::ExtractIf", "btree_map::ExtractIf"),
+    ("alloc::collections::btree::map::IntoIter", "btree_map::IntoIter"),
+    ("alloc::collections::btree::map::IntoKeys", "btree_map::IntoKeys"),
+    ("alloc::collections::btree::map::IntoValues", "btree_map::IntoValues"),
+    ("alloc::collections::btree::map::Iter", "btree_map::Iter"),
+    ("alloc::collections::btree::map::IterMut", "btree_map::IterMut"),
+    ("alloc::collections::btree::map::Keys", "btree_map::Keys"),
+    ("alloc::collections::btree::map::Range", "btree_map::Range"),
+    ("alloc::collections::btree::map::RangeMut", "RangeMut"),
+    ("alloc::collections::btree::map::Values", "btree_map::Values"),
+    ("alloc::collections::btree::map::ValuesMut", "btree_map::ValuesMut"),
+    ("alloc::collections::btree::map::entry::Entry", "btree_map::Entry"),
+    ("alloc::collections::btree::map::entry::OccupiedEntry", "btree_map::OccupiedEntry"),
+    ("alloc::collections::btree::map::entry::VacantEntry", "btree_map::VacantEntry"),
+    ("alloc::collections::btree::set::BTreeSet", "BTreeSet"),
+    ("alloc::collections::btree::set::Difference", "btree_set::Difference"),
+    ("alloc::collections::btree::set::ExtractIf", "btree_set::ExtractIf"),
+    ("alloc::collections::btree::set::Intersection", "btree_set::Intersection"),
+    ("alloc::collections::btree::set::IntoIter", "btree_set::IntoIter"),
+    ("alloc::collections::btree::set::Iter", "btree_set::Iter"),
+    ("alloc::collections::btree::set::Range", "btree_set::Range"),
+    ("alloc::collections::btree::set::SymmetricDifference", "btree_set::SymmetricDifference"),
+    ("alloc::collections::btree::set::Union", "btree_set::Union"),
+    ("alloc::collections::linked_list::ExtractIf", "linked_list::ExtractIf"),
+    ("alloc::collections::linked_list::IntoIter", "linked_list::IntoIter"),
+    ("alloc::collections::linked_list::Iter", "linked_list::Iter"),
+    ("alloc::collections::linked_list::IterMut", "linked_list::IterMut"),
+    ("alloc::collections::linked_list::LinkedList", "LinkedList"),
+    ("alloc::collections::vec_deque::VecDeque", "VecDeque"),
+    ("alloc::collections::vec_deque::drain::Drain", "vec_deque::Drain"),
+    ("alloc::collections::vec_deque::into_iter::IntoIter", "vec_deque::IntoIter"),
+    ("alloc::collections::vec_deque::iter::Iter", "vec_deque::Iter"),
+    ("alloc::collections::vec_deque::iter_mut::IterMut", "vec_deque::IterMut"),
+    ("alloc::ffi::c_str::CString", "CString"),
+    ("alloc::ffi::c_str::FromVecWithNulError", "FromVecWithNulError"),
+    ("alloc::ffi::c_str::IntoStringError", "IntoStringError"),
+    ("alloc::ffi::c_str::NulError", "NulError"),
+    ("alloc::rc::Rc", "Rc"),
+    ("alloc::rc::Weak", "rc::Weak"),
+    ("alloc::string::Drain", "string::Drain"),
+    ("alloc::string::FromUtf16Error", "FromUtf16Error"),
+    ("alloc::string::FromUtf8Error", "FromUtf8Error"),
+    ("alloc::string::String", "String"),
+    ("alloc::string::ToString", "ToString"),
+    ("alloc::sync::Arc", "Arc"),
+    ("alloc::sync::Weak", "sync::Weak"),
+    ("alloc::task::Wake", "Wake"),
+    ("alloc::vec::Vec", "Vec"),
+    ("alloc::vec::drain::Drain", "vec::Drain"),
+    ("alloc::vec::extract_if::ExtractIf", "vec::ExtractIf"),
+    ("alloc::vec::into_iter::IntoIter", "vec::IntoIter"),
+    ("alloc::vec::splice::Splice", "Splice"),
+    ("core::alloc::global::GlobalAlloc", "GlobalAlloc"),
+    ("core::alloc::layout::Layout", "Layout"),
+    ("core::alloc::layout::LayoutError", "LayoutError"),
+    ("core::any::Any", "Any"),
+    ("core::any::TypeId", "TypeId"),
+    ("core::array::TryFromSliceError", "TryFromSliceError"),
+    ("core::array::iter::IntoIter", "array::IntoIter"),
+    ("core::ascii::EscapeDefault", "ascii::EscapeDefault"),
+    ("core::borrow::Borrow", "Borrow"),
+    ("core::borrow::BorrowMut", "BorrowMut"),
+    ("core::cell::BorrowError", "BorrowError"),
+    ("core::cell::BorrowMutError", "BorrowMutError"),
+    ("core::cell::Cell", "Cell"),
+    ("core::cell::Ref", "Ref"),
+    ("core::cell::RefCell", "RefCell"),
+    ("core::cell::RefMut", "RefMut"),
+    ("core::cell::UnsafeCell", "UnsafeCell"),
+    ("core::cell::lazy::LazyCell", "LazyCell"),
+    ("core::cell::once::OnceCell", "OnceCell"),
+    ("core::char::EscapeDebug", "char::EscapeDebug"),
+    ("core::char::EscapeDefault", "char::EscapeDefault"),
+    ("core::char::EscapeUnicode", "char::EscapeUnicode"),
+    ("core::char::ToLowercase", "ToLowercase"),
+    ("core::char::ToUppercase", "ToUppercase"),
+    ("core::char::TryFromCharError", "TryFromCharError"),
+    ("core::char::convert::CharTryFromError", "CharTryFromError"),
+    ("core::char::convert::ParseCharError", "ParseCharError"),
+    ("core::char::decode::DecodeUtf16", "DecodeUtf16"),
+    ("core::char::decode::DecodeUtf16Error", "DecodeUtf16Error"),
+    ("core::clone::Clone", "Clone"),
+    ("core::cmp::Eq", "Eq"),
+    ("core::cmp::Ord", "Ord"),
+    ("core::cmp::Ordering", "cmp::Ordering"),
+    ("core::cmp::PartialEq", "PartialEq"),
+    ("core::cmp::PartialOrd", "PartialOrd"),
+    ("core::cmp::Reverse", "Reverse"),
+    ("core::convert::AsMut", "AsMut"),
+    ("core::convert::AsRef", "AsRef"),
+    ("core::convert::From", "From"),
+    ("core::convert::Infallible", "Infallible"),
+    ("core::convert::Into", "Into"),
+    ("core::convert::TryFrom", "TryFrom"),
+    ("core::convert::TryInto", "TryInto"),
+    ("core::default::Default", "Default"),
+    ("core::error::Error", "error::Error"),
+    ("core::ffi::c_str::CStr", "CStr"),
+    ("core::ffi::c_str::FromBytesUntilNulError", "FromBytesUntilNulError"),
+    ("core::ffi::c_str::FromBytesWithNulError", "FromBytesWithNulError"),
+    ("core::ffi::c_void", "c_void"),
+    ("core::fmt::Alignment", "Alignment"),
+    ("core::fmt::Arguments", "Arguments"),
+    ("core::fmt::Binary", "Binary"),
+    ("core::fmt::Debug", "Debug"),
+    ("core::fmt::Display", "fmt::Display"),
+    ("core::fmt::Error", "fmt::Error"),
+    ("core::fmt::Formatter", "Formatter"),
+    ("core::fmt::LowerExp", "LowerExp"),
+    ("core::fmt::LowerHex", "LowerHex"),
+    ("core::fmt::Octal", "Octal"),
+    ("core::fmt::Pointer", "Pointer"),
+    ("core::fmt::UpperExp", "UpperExp"),
+    ("core::fmt::UpperHex", "UpperHex"),
+    ("core::fmt::Write", "fmt::Write"),
+    ("core::fmt::builders::DebugList", "DebugList"),
+    ("core::fmt::builders::DebugMap", "DebugMap"),
+    ("core::fmt::builders::DebugSet", "DebugSet"),
+    ("core::fmt::builders::DebugStruct", "DebugStruct"),
+    ("core::fmt::builders::DebugTuple", "DebugTuple"),
+    ("core::fmt::builders::FromFn", "fmt::FromFn"),
+    ("core::future::future::Future", "Future"),
+    ("core::future::into_future::IntoFuture", "IntoFuture"),
+    ("core::future::pending::Pending", "Pending"),
+    ("core::future::poll_fn::PollFn", "PollFn"),
+    ("core::future::ready::Ready", "Ready"),
+    ("core::hash::BuildHasher", "BuildHasher"),
+    ("core::hash::BuildHasherDefault", "BuildHasherDefault"),
+    ("core::hash::Hash", "Hash"),
+    ("core::hash::Hasher", "Hasher"),
+    ("core::hash::sip::SipHasher", "SipHasher"),
+    ("core::iter::adapters::chain::Chain", "iter::Chain"),
+    ("core::iter::adapters::cloned::Cloned", "Cloned"),
+    ("core::iter::adapters::copied::Copied", "Copied"),
+    ("core::iter::adapters::cycle::Cycle", "Cycle"),
+    ("core::iter::adapters::enumerate::Enumerate", "Enumerate"),
+    ("core::iter::adapters::filter::Filter", "Filter"),
+    ("core::iter::adapters::filter_map::FilterMap", "FilterMap"),
+    ("core::iter::adapters::flatten::FlatMap", "FlatMap"),
+    ("core::iter::adapters::flatten::Flatten", "Flatten"),
+    ("core::iter::adapters::fuse::Fuse", "Fuse"),
+    ("core::iter::adapters::inspect::Inspect", "Inspect"),
+    ("core::iter::adapters::map::Map", "Map"),
+    ("core::iter::adapters::map_while::MapWhile", "MapWhile"),
+    ("core::iter::adapters::peekable::Peekable", "Peekable"),
+    ("core::iter::adapters::rev::Rev", "Rev"),
+    ("core::iter::adapters::scan::Scan", "Scan"),
+    ("core::iter::adapters::skip::Skip", "Skip"),
+    ("core::iter::adapters::skip_while::SkipWhile", "SkipWhile"),
+    ("core::iter::adapters::step_by::StepBy", "StepBy"),
+    ("core::iter::adapters::take::Take", "iter::Take"),
+    ("core::iter::adapters::take_while::TakeWhile", "TakeWhile"),
+    ("core::iter::adapters::zip::Zip", "Zip"),
+    ("core::iter::sources::empty::Empty", "iter::Empty"),
+    ("core::iter::sources::from_fn::FromFn", "iter::FromFn"),
+    ("core::iter::sources::once::Once", "iter::Once"),
+    ("core::iter::sources::once_with::OnceWith", "OnceWith"),
+    ("core::iter::sources::repeat::Repeat", "iter::Repeat"),
+    ("core::iter::sources::repeat_n::RepeatN", "RepeatN"),
+    ("core::iter::sources::repeat_with::RepeatWith", "RepeatWith"),
+    ("core::iter::sources::successors::Successors", "Successors"),
+    ("core::iter::traits::accum::Product", "Product"),
+    ("core::iter::traits::accum::Sum", "Sum"),
+    ("core::iter::traits::collect::Extend", "Extend"),
+    ("core::iter::traits::collect::FromIterator", "FromIterator"),
+    ("core::iter::traits::collect::IntoIterator", "IntoIterator"),
+    ("core::iter::traits::double_ended::DoubleEndedIterator", "DoubleEndedIterator"),
+    ("core::iter::traits::exact_size::ExactSizeIterator", "ExactSizeIterator"),
+    ("core::iter::traits::iterator::Iterator", "Iterator"),
+    ("core::iter::traits::marker::FusedIterator", "FusedIterator"),
+    ("core::marker::Copy", "Copy"),
+    ("core::marker::PhantomData", "PhantomData"),
+    ("core::marker::PhantomPinned", "PhantomPinned"),
+    ("core::marker::Send", "Send"),
+    ("core::marker::Sized", "Sized"),
+    ("core::marker::Sync", "Sync"),
+    ("core::marker::Unpin", "Unpin"),
+    ("core::mem::Discriminant", "Discriminant"),
+    ("core::mem::manually_drop::ManuallyDrop", "ManuallyDrop"),
+    ("core::mem::maybe_uninit::MaybeUninit", "MaybeUninit"),
+    ("core::net::ip_addr::IpAddr", "IpAddr"),
+    ("core::net::ip_addr::Ipv4Addr", "Ipv4Addr"),
+    ("core::net::ip_addr::Ipv6Addr", "Ipv6Addr"),
+    ("core::net::parser::AddrParseError", "AddrParseError"),
+    ("core::net::socket_addr::SocketAddr", "net::SocketAddr"),
+    ("core::net::socket_addr::SocketAddrV4", "SocketAddrV4"),
+    ("core::net::socket_addr::SocketAddrV6", "SocketAddrV6"),
+    ("core::num::FpCategory", "FpCategory"),
+    ("core::num::dec2flt::ParseFloatError", "ParseFloatError"),
+    ("core::num::error::IntErrorKind", "IntErrorKind"),
+    ("core::num::error::ParseIntError", "ParseIntError"),
+    ("core::num::error::TryFromIntError", "TryFromIntError"),
+    ("core::num::nonzero::NonZero", "NonZero"),
+    ("core::num::nonzero::NonZeroI128", "NonZeroI128"),
+    ("core::num::nonzero::NonZeroI16", "NonZeroI16"),
+    ("core::num::nonzero::NonZeroI32", "NonZeroI32"),
+    ("core::num::nonzero::NonZeroI64", "NonZeroI64"),
+    ("core::num::nonzero::NonZeroI8", "NonZeroI8"),
+    ("core::num::nonzero::NonZeroIsize", "NonZeroIsize"),
+    ("core::num::nonzero::NonZeroU128", "NonZeroU128"),
+    ("core::num::nonzero::NonZeroU16", "NonZeroU16"),
+    ("core::num::nonzero::NonZeroU32", "NonZeroU32"),
+    ("core::num::nonzero::NonZeroU64", "NonZeroU64"),
+    ("core::num::nonzero::NonZeroU8", "NonZeroU8"),
+    ("core::num::nonzero::NonZeroUsize", "NonZeroUsize"),
+    ("core::num::saturating::Saturating", "Saturating"),
+    ("core::num::wrapping::Wrapping", "Wrapping"),
+    ("core::ops::arith::Add", "Add"),
+    ("core::ops::arith::AddAssign", "AddAssign"),
+    ("core::ops::arith::Div", "Div"),
+    ("core::ops::arith::DivAssign", "DivAssign"),
+    ("core::ops::arith::Mul", "Mul"),
+    ("core::ops::arith::MulAssign", "MulAssign"),
+    ("core::ops::arith::Neg", "Neg"),
+    ("core::ops::arith::Rem", "Rem"),
+    ("core::ops::arith::RemAssign", "RemAssign"),
+    ("core::ops::arith::Sub", "Sub"),
+    ("core::ops::arith::SubAssign", "SubAssign"),
+    ("core::ops::bit::BitAnd", "BitAnd"),
+    ("core::ops::bit::BitAndAssign", "BitAndAssign"),
+    ("core::ops::bit::BitOr", "BitOr"),
+    ("core::ops::bit::BitOrAssign", "BitOrAssign"),
+    ("core::ops::bit::BitXor", "BitXor"),
+    ("core::ops::bit::BitXorAssign", "BitXorAssign"),
+    ("core::ops::bit::Not", "Not"),
+    ("core::ops::bit::Shl", "Shl"),
+    ("core::ops::bit::ShlAssign", "ShlAssign"),
+    ("core::ops::bit::Shr", "Shr"),
+    ("core::ops::bit::ShrAssign", "ShrAssign"),
+    ("core::ops::control_flow::ControlFlow", "ControlFlow"),
+    ("core::ops::deref::Deref", "Deref"),
+    ("core::ops::deref::DerefMut", "DerefMut"),
+    ("core::ops::drop::Drop", "Drop"),
+    ("core::ops::function::Fn", "Fn"),
+    ("core::ops::function::FnMut", "FnMut"),
+    ("core::ops::function::FnOnce", "FnOnce"),
+    ("core::ops::index::Index", "Index"),
+    ("core::ops::index::IndexMut", "IndexMut"),
+    ("core::ops::range::Bound", "Bound"),
+    ("core::ops::range::Range", "ops::Range"),
+    ("core::ops::range::RangeBounds", "RangeBounds"),
+    ("core::ops::range::RangeFrom", "RangeFrom"),
+    ("core::ops::range::RangeFull", "RangeFull"),
+    ("core::ops::range::RangeInclusive", "ops::RangeInclusive"),
+    ("core::ops::range::RangeTo", "RangeTo"),
+    ("core::ops::range::RangeToInclusive", "RangeToInclusive"),
+    ("core::option::IntoIter", "option::IntoIter"),
+    ("core::option::Iter", "option::Iter"),
+    ("core::option::IterMut", "option::IterMut"),
+    ("core::option::Option", "Option"),
+    ("core::panic::location::Location", "Location"),
+    ("core::panic::unwind_safe::AssertUnwindSafe", "AssertUnwindSafe"),
+    ("core::panic::unwind_safe::RefUnwindSafe", "RefUnwindSafe"),
+    ("core::panic::unwind_safe::UnwindSafe", "UnwindSafe"),
+    ("core::pin::Pin", "Pin"),
+    ("core::ptr::non_null::NonNull", "NonNull"),
+    ("core::range::RangeInclusive", "range::RangeInclusive"),
+    ("core::range::iter::RangeInclusiveIter", "RangeInclusiveIter"),
+    ("core::result::IntoIter", "result::IntoIter"),
+    ("core::result::Iter", "result::Iter"),
+    ("core::result::IterMut", "result::IterMut"),
+    ("core::result::Result", "Result"),
+    ("core::slice::GetDisjointMutError", "GetDisjointMutError"),
+    ("core::slice::ascii::EscapeAscii", "EscapeAscii"),
+    ("core::slice::index::SliceIndex", "SliceIndex"),
+    ("core::slice::iter::ArrayWindows", "ArrayWindows"),
+    ("core::slice::iter::ChunkBy", "ChunkBy"),
+    ("core::slice::iter::ChunkByMut", "ChunkByMut"),
+    ("core::slice::iter::Chunks", "Chunks"),
+    ("core::slice::iter::ChunksExact", "ChunksExact"),
+    ("core::slice::iter::ChunksExactMut", "ChunksExactMut"),
+    ("core::slice::iter::ChunksMut", "ChunksMut"),
+    ("core::slice::iter::Iter", "slice::Iter"),
+    ("core::slice::iter::IterMut", "slice::IterMut"),
+    ("core::slice::iter::RChunks", "RChunks"),
+    ("core::slice::iter::RChunksExact", "RChunksExact"),
+    ("core::slice::iter::RChunksExactMut", "RChunksExactMut"),
+    ("core::slice::iter::RChunksMut", "RChunksMut"),
+    ("core::slice::iter::RSplit", "slice::RSplit"),
+    ("core::slice::iter::RSplitMut", "RSplitMut"),
+    ("core::slice::iter::RSplitN", "slice::RSplitN"),
+    ("core::slice::iter::RSplitNMut", "RSplitNMut"),
+    ("core::slice::iter::Split", "slice::Split"),
+    ("core::slice::iter::SplitInclusive", "slice::SplitInclusive"),
+    ("core::slice::iter::SplitInclusiveMut", "SplitInclusiveMut"),
+    ("core::slice::iter::SplitMut", "SplitMut"),
+    ("core::slice::iter::SplitN", "slice::SplitN"),
+    ("core::slice::iter::SplitNMut", "SplitNMut"),
+    ("core::slice::iter::Windows", "Windows"),
+    ("core::str::error::ParseBoolError", "ParseBoolError"),
+    ("core::str::error::Utf8Error", "Utf8Error"),
+    ("core::str::iter::Bytes", "str::Bytes"),
+    ("core::str::iter::CharIndices", "CharIndices"),
+    ("core::str::iter::Chars", "Chars"),
+    ("core::str::iter::EncodeUtf16", "EncodeUtf16"),
+    ("core::str::iter::EscapeDebug", "str::EscapeDebug"),
+    ("core::str::iter::EscapeDefault", "str::EscapeDefault"),
+    ("core::str::iter::EscapeUnicode", "str::EscapeUnicode"),
+    ("core::str::iter::Lines", "str::Lines"),
+    ("core::str::iter::LinesAny", "LinesAny"),
+    ("core::str::iter::MatchIndices", "MatchIndices"),
+    ("core::str::iter::Matches", "Matches"),
+    ("core::str::iter::RMatchIndices", "RMatchIndices"),
+    ("core::str::iter::RMatches", "RMatches"),
+    ("core::str::iter::RSplit", "str::RSplit"),
+    ("core::str::iter::RSplitN", "str::RSplitN"),
+    ("core::str::iter::RSplitTerminator", "RSplitTerminator"),
+    ("core::str::iter::Split", "str::Split"),
+    ("core::str::iter::SplitAsciiWhitespace", "SplitAsciiWhitespace"),
+    ("core::str::iter::SplitInclusive", "str::SplitInclusive"),
+    ("core::str::iter::SplitN", "str::SplitN"),
+    ("core::str::iter::SplitTerminator", "SplitTerminator"),
+    ("core::str::iter::SplitWhitespace", "SplitWhitespace"),
+    ("core::str::lossy::Utf8Chunk", "Utf8Chunk"),
+    ("core::str::lossy::Utf8Chunks", "Utf8Chunks"),
+    ("core::str::traits::FromStr", "FromStr"),
+    ("core::sync::atomic::AtomicBool", "AtomicBool"),
+    ("core::sync::atomic::AtomicI16", "AtomicI16"),
+    ("core::sync::atomic::AtomicI32", "AtomicI32"),
+    ("core::sync::atomic::AtomicI64", "AtomicI64"),
+    ("core::sync::atomic::AtomicI8", "AtomicI8"),
+    ("core::sync::atomic::AtomicIsize", "AtomicIsize"),
+    ("core::sync::atomic::AtomicPtr", "AtomicPtr"),
+    ("core::sync::atomic::AtomicU16", "AtomicU16"),
+    ("core::sync::atomic::AtomicU32", "AtomicU32"),
+    ("core::sync::atomic::AtomicU64", "AtomicU64"),
+    ("core::sync::atomic::AtomicU8", "AtomicU8"),
+    ("core::sync::atomic::AtomicUsize", "AtomicUsize"),
+    ("core::sync::atomic::Ordering", "atomic::Ordering"),
+    ("core::task::poll::Poll", "Poll"),
+    ("core::task::wake::Context", "Context"),
+    ("core::task::wake::RawWaker", "RawWaker"),
+    ("core::task::wake::RawWakerVTable", "RawWakerVTable"),
+    ("core::task::wake::Waker", "Waker"),
+    ("core::time::Duration", "Duration"),
+    ("core::time::TryFromFloatSecsError", "TryFromFloatSecsError"),
+    ("std::alloc::System", "System"),
+    ("std::ascii::AsciiExt", "AsciiExt"),
+    ("std::backtrace::Backtrace", "Backtrace"),
+    ("std::backtrace::BacktraceStatus", "BacktraceStatus"),
+    ("std::collections::hash::map::Drain", "hash_map::Drain"),
+    ("std::collections::hash::map::Entry", "hash_map::Entry"),
+    ("std::collections::hash::map::ExtractIf", "hash_map::ExtractIf"),
+    ("std::collections::hash::map::HashMap", "HashMap"),
+    ("std::collections::hash::map::IntoIter", "hash_map::IntoIter"),
+    ("std::collections::hash::map::IntoKeys", "hash_map::IntoKeys"),
+    ("std::collections::hash::map::IntoValues", "hash_map::IntoValues"),
+    ("std::collections::hash::map::Iter", "hash_map::Iter"),
+    ("std::collections::hash::map::IterMut", "hash_map::IterMut"),
+    ("std::collections::hash::map::Keys", "hash_map::Keys"),
+    ("std::collections::hash::map::OccupiedEntry", "hash_map::OccupiedEntry"),
+    ("std::collections::hash::map::VacantEntry", "hash_map::VacantEntry"),
+    ("std::collections::hash::map::Values", "hash_map::Values"),
+    ("std::collections::hash::map::ValuesMut", "hash_map::ValuesMut"),
+    ("std::collections::hash::set::Difference", "hash_set::Difference"),
+    ("std::collections::hash::set::Drain", "hash_set::Drain"),
+    ("std::collections::hash::set::ExtractIf", "hash_set::ExtractIf"),
+    ("std::collections::hash::set::HashSet", "HashSet"),
+    ("std::collections::hash::set::Intersection", "hash_set::Intersection"),
+    ("std::collections::hash::set::IntoIter", "hash_set::IntoIter"),
+    ("std::collections::hash::set::Iter", "hash_set::Iter"),
+    ("std::collections::hash::set::SymmetricDifference", "hash_set::SymmetricDifference"),
+    ("std::collections::hash::set::Union", "hash_set::Union"),
+    ("std::env::Args", "Args"),
+    ("std::env::ArgsOs", "ArgsOs"),
+    ("std::env::JoinPathsError", "JoinPathsError"),
+    ("std::env::SplitPaths", "SplitPaths"),
+    ("std::env::VarError", "VarError"),
+    ("std::env::Vars", "Vars"),
+    ("std::env::VarsOs", "VarsOs"),
+    ("std::error::Error", "error::Error"),
+    ("std::ffi::os_str::Display", "os_str::Display"),
+    ("std::ffi::os_str::OsStr", "OsStr"),
+    ("std::ffi::os_str::OsString", "OsString"),
+    ("std::fs::DirBuilder", "DirBuilder"),
+    ("std::fs::DirEntry", "DirEntry"),
+    ("std::fs::File", "File"),
+    ("std::fs::FileTimes", "FileTimes"),
+    ("std::fs::FileType", "FileType"),
+    ("std::fs::Metadata", "Metadata"),
+    ("std::fs::OpenOptions", "OpenOptions"),
+    ("std::fs::Permissions", "Permissions"),
+    ("std::fs::ReadDir", "ReadDir"),
+    ("std::fs::TryLockError", "fs::TryLockError"),
+    ("std::hash::random::DefaultHasher", "DefaultHasher"),
+    ("std::hash::random::RandomState", "RandomState"),
+    ("std::io::BufRead", "BufRead"),
+    ("std::io::Bytes", "io::Bytes"),
+    ("std::io::Chain", "io::Chain"),
+    ("std::io::IoSlice", "IoSlice"),
+    ("std::io::IoSliceMut", "IoSliceMut"),
+    ("std::io::Lines", "io::Lines"),
+    ("std::io::Read", "Read"),
+    ("std::io::Seek", "Seek"),
+    ("std::io::SeekFrom", "SeekFrom"),
+    ("std::io::Split", "io::Split"),
+    ("std::io::Take", "io::Take"),
+    ("std::io::Write", "io::Write"),
+    ("std::io::buffered::IntoInnerError", "IntoInnerError"),
+    ("std::io::buffered::bufreader::BufReader", "BufReader"),
+    ("std::io::buffered::bufwriter::BufWriter", "BufWriter"),
+    ("std::io::buffered::bufwriter::WriterPanicked", "WriterPanicked"),
+    ("std::io::buffered::linewriter::LineWriter", "LineWriter"),
+    ("std::io::cursor::Cursor", "Cursor"),
+    ("std::io::error::Error", "io::Error"),
+    ("std::io::error::ErrorKind", "ErrorKind"),
+    ("std::io::pipe::PipeReader", "PipeReader"),
+    ("std::io::pipe::PipeWriter", "PipeWriter"),
+    ("std::io::stdio::IsTerminal", "IsTerminal"),
+    ("std::io::stdio::Stderr", "Stderr"),
+    ("std::io::stdio::StderrLock", "StderrLock"),
+    ("std::io::stdio::Stdin", "Stdin"),
+    ("std::io::stdio::StdinLock", "StdinLock"),
+    ("std::io::stdio::Stdout", "Stdout"),
+    ("std::io::stdio::StdoutLock", "StdoutLock"),
+    ("std::io::util::Empty", "io::Empty"),
+    ("std::io::util::Repeat", "io::Repeat"),
+    ("std::io::util::Sink", "Sink"),
+    ("std::net::Shutdown", "Shutdown"),
+    ("std::net::socket_addr::ToSocketAddrs", "ToSocketAddrs"),
+    ("std::net::tcp::Incoming", "net::Incoming"),
+    ("std::net::tcp::TcpListener", "TcpListener"),
+    ("std::net::tcp::TcpStream", "TcpStream"),
+    ("std::net::udp::UdpSocket", "UdpSocket"),
+    ("std::os::fd::owned::AsFd", "AsFd"),
+    ("std::os::fd::owned::BorrowedFd", "BorrowedFd"),
+    ("std::os::fd::owned::OwnedFd", "OwnedFd"),
+    ("std::os::fd::raw::AsRawFd", "AsRawFd"),
+    ("std::os::fd::raw::FromRawFd", "FromRawFd"),
+    ("std::os::fd::raw::IntoRawFd", "IntoRawFd"),
+    ("std::os::linux::fs::MetadataExt", "linux::fs::MetadataExt"),
+    ("std::os::linux::raw::arch::stat", "stat"),
+    ("std::os::net::linux_ext::addr::SocketAddrExt", "SocketAddrExt"),
+    ("std::os::net::linux_ext::tcp::TcpStreamExt", "TcpStreamExt"),
+    ("std::os::unix::ffi::os_str::OsStrExt", "OsStrExt"),
+    ("std::os::unix::ffi::os_str::OsStringExt", "OsStringExt"),
+    ("std::os::unix::fs::DirBuilderExt", "DirBuilderExt"),
+    ("std::os::unix::fs::DirEntryExt", "DirEntryExt"),
+    ("std::os::unix::fs::FileExt", "FileExt"),
+    ("std::os::unix::fs::FileTypeExt", "FileTypeExt"),
+    ("std::os::unix::fs::MetadataExt", "unix::fs::MetadataExt"),
+    ("std::os::unix::fs::OpenOptionsExt", "OpenOptionsExt"),
+    ("std::os::unix::fs::PermissionsExt", "PermissionsExt"),
+    ("std::os::unix::net::addr::SocketAddr", "unix::net::SocketAddr"),
+    ("std::os::unix::net::datagram::UnixDatagram", "UnixDatagram"),
+    ("std::os::unix::net::listener::Incoming", "unix::net::Incoming"),
+    ("std::os::unix::net::listener::UnixListener", "UnixListener"),
+    ("std::os::unix::net::stream::UnixStream", "UnixStream"),
+    ("std::os::unix::process::CommandExt", "CommandExt"),
+    ("std::os::unix::process::ExitStatusExt", "ExitStatusExt"),
+    ("std::os::unix::thread::JoinHandleExt", "JoinHandleExt"),
+    ("std::panic::PanicHookInfo", "PanicHookInfo"),
+    ("std::path::Ancestors", "Ancestors"),
+    ("std::path::Component", "Component"),
+    ("std::path::Components", "Components"),
+    ("std::path::Display", "path::Display"),
+    ("std::path::Iter", "path::Iter"),
+    ("std::path::Path", "Path"),
+    ("std::path::PathBuf", "PathBuf"),
+    ("std::path::Prefix", "Prefix"),
+    ("std::path::PrefixComponent", "PrefixComponent"),
+    ("std::path::StripPrefixError", "StripPrefixError"),
+    ("std::process::Child", "Child"),
+    ("std::process::ChildStderr", "ChildStderr"),
+    ("std::process::ChildStdin", "ChildStdin"),
+    ("std::process::ChildStdout", "ChildStdout"),
+    ("std::process::Command", "Command"),
+    ("std::process::CommandArgs", "CommandArgs"),
+    ("std::process::CommandEnvs", "CommandEnvs"),
+    ("std::process::ExitCode", "ExitCode"),
+    ("std::process::ExitStatus", "ExitStatus"),
+    ("std::process::Output", "Output"),
+    ("std::process::Stdio", "Stdio"),
+    ("std::process::Termination", "Termination"),
+    ("std::sync::WaitTimeoutResult", "WaitTimeoutResult"),
+    ("std::sync::barrier::Barrier", "Barrier"),
+    ("std::sync::barrier::BarrierWaitResult", "BarrierWaitResult"),
+    ("std::sync::condvar::Condvar", "Condvar"),
+    ("std::sync::condvar::WaitTimeoutResult", "WaitTimeoutResult"),
+    ("std::sync::lazy_lock::LazyLock", "LazyLock"),
+    ("std::sync::mpsc::IntoIter", "mpsc::IntoIter"),
+    ("std::sync::mpsc::Iter", "mpsc::Iter"),
+    ("std::sync::mpsc::Receiver", "Receiver"),
+    ("std::sync::mpsc::RecvError", "RecvError"),
+    ("std::sync::mpsc::RecvTimeoutError", "RecvTimeoutError"),
+    ("std::sync::mpsc::SendError", "SendError"),
+    ("std::sync::mpsc::Sender", "Sender"),
+    ("std::sync::mpsc::SyncSender", "SyncSender"),
+    ("std::sync::mpsc::TryIter", "TryIter"),
+    ("std::sync::mpsc::TryRecvError", "TryRecvError"),
+    ("std::sync::mpsc::TrySendError", "TrySendError"),
+    ("std::sync::mutex::Mutex", "Mutex"),
+    ("std::sync::mutex::MutexGuard", "MutexGuard"),
+    ("std::sync::once::Once", "sync::Once"),
+    ("std::sync::once::OnceState", "OnceState"),
+    ("std::sync::once_lock::OnceLock", "OnceLock"),
+    ("std::sync::poison::PoisonError", "PoisonError"),
+    ("std::sync::poison::TryLockError", "sync::TryLockError"),
+    ("std::sync::poison::condvar::Condvar", "Condvar"),
+    ("std::sync::poison::mutex::Mutex", "Mutex"),
+    ("std::sync::poison::mutex::MutexGuard", "MutexGuard"),
+    ("std::sync::poison::rwlock::RwLock", "RwLock"),
+    ("std::sync::poison::rwlock::RwLockReadGuard", "RwLockReadGuard"),
+    ("std::sync::poison::rwlock::RwLockWriteGuard", "RwLockWriteGuard"),
+    ("std::sync::rwlock::RwLock", "RwLock"),
+    ("std::sync::rwlock::RwLockReadGuard", "RwLockReadGuard"),
+    ("std::sync::rwlock::RwLockWriteGuard", "RwLockWriteGuard"),
+    ("std::thread::builder::Builder", "Builder"),
+    ("std::thread::id::ThreadId", "ThreadId"),
+    ("std::thread::join_handle::JoinHandle", "JoinHandle"),
+    ("std::thread::local::AccessError", "AccessError"),
+    ("std::thread::local::LocalKey", "LocalKey"),
+    ("std::thread::scoped::Scope", "Scope"),
+    ("std::thread::scoped::ScopedJoinHandle", "ScopedJoinHandle"),
+    ("std::thread::thread::Thread", "Thread"),
+    ("std::time::Instant", "Instant"),
+    ("std::time::SystemTime", "SystemTime"),
+    ("std::time::SystemTimeError", "SystemTimeError"),
 ]
@@ -0,0 +1,509 @@
+// Every type in `pretty.expr.rs`, and the pretty path of its outermost type.
+// (Traits that can't be made into `dyn` objects are left out.)
+{
+    check! {
+        dyn std::alloc::GlobalAlloc => "GlobalAlloc",
+        dyn std::any::Any => "Any",
+        dyn std::borrow::Borrow<u8> => "Borrow",
+        dyn std::borrow::BorrowMut<u8> => "BorrowMut",
+        dyn std::cmp::PartialEq<u8> => "PartialEq",
+        dyn std::cmp::PartialOrd<u8> => "PartialOrd",
+        dyn std::convert::AsMut<u8> => "AsMut",
+        dyn std::convert::AsRef<u8> => "AsRef",
+        dyn std::error::Error => "error::Error",
+        dyn std::fmt::Binary => "Binary",
+        dyn std::fmt::Debug => "Debug",
+        dyn std::fmt::Display => "fmt::Display",
+        dyn std::fmt::LowerExp => "LowerExp",
+        dyn std::fmt::LowerHex => "LowerHex",
+        dyn std::fmt::Octal => "Octal",
+        dyn std::fmt::Pointer => "Pointer",
+        dyn std::fmt::UpperExp => "UpperExp",
+        dyn std::fmt::UpperHex => "UpperHex",
+        dyn std::fmt::Write => "fmt::Write",
+        dyn std::future::Future<Output = u8> => "Future",
+        dyn std::future::IntoFuture<Output = u8, IntoFuture = u8> => "IntoFuture",
+        dyn std::hash::BuildHasher<Hasher = u8> => "BuildHasher",
+        dyn std::hash::Hasher => "Hasher",
+        dyn std::io::BufRead => "BufRead",
+        dyn std::io::IsTerminal => "IsTerminal",
+        dyn std::io::Read => "Read",
+        dyn std::io::Seek => "Seek",
+        dyn std::io::Write => "io::Write",
+        dyn std::iter::DoubleEndedIterator<Item = u8> => "DoubleEndedIterator",
+        dyn std::iter::ExactSizeIterator<Item = u8> => "ExactSizeIterator",
+        dyn std::iter::FusedIterator<Item = u8> => "FusedIterator",
+        dyn std::iter::IntoIterator<Item = u8, IntoIter = u8> => "IntoIterator",
+        dyn std::iter::Iterator<Item = u8> => "Iterator",
+        dyn std::marker::Send => "Send",
+        dyn std::marker::Sync => "Sync",
+        dyn std::marker::Unpin => "Unpin",
+        dyn std::net::ToSocketAddrs<Iter = u8> => "ToSocketAddrs",
+        dyn std::ops::Add<u8, Output = u8> => "Add",
+        dyn std::ops::AddAssign<u8> => "AddAssign",
+        dyn std::ops::BitAnd<u8, Output = u8> => "BitAnd",
+        dyn std::ops::BitAndAssign<u8> => "BitAndAssign",
+        dyn std::ops::BitOr<u8, Output = u8> => "BitOr",
+        dyn std::ops::BitOrAssign<u8> => "BitOrAssign",
+        dyn std::ops::BitXor<u8, Output = u8> => "BitXor",
+        dyn std::ops::BitXorAssign<u8> => "BitXorAssign",
+        dyn std::ops::Deref<Target = u8> => "Deref",
+        dyn std::ops::DerefMut<Target = u8> => "DerefMut",
+        dyn std::ops::Div<u8, Output = u8> => "Div",
+        dyn std::ops::DivAssign<u8> => "DivAssign",
+        dyn std::ops::Drop => "Drop",
+        dyn std::ops::Fn(u8) => "Fn",
+        dyn std::ops::FnMut(u8) => "FnMut",
+        dyn std::ops::FnOnce(u8) => "FnOnce",
+        dyn std::ops::Index<u8, Output = u8> => "Index",
+        dyn std::ops::IndexMut<u8, Output = u8> => "IndexMut",
+        dyn std::ops::Mul<u8, Output = u8> => "Mul",
+        dyn std::ops::MulAssign<u8> => "MulAssign",
+        dyn std::ops::Neg<Output = u8> => "Neg",
+        dyn std::ops::Not<Output = u8> => "Not",
+        dyn std::ops::Rem<u8, Output = u8> => "Rem",
+        dyn std::ops::RemAssign<u8> => "RemAssign",
+        dyn std::ops::Shl<u8, Output = u8> => "Shl",
+        dyn std::ops::ShlAssign<u8> => "ShlAssign",
+        dyn std::ops::Shr<u8, Output = u8> => "Shr",
+        dyn std::ops::ShrAssign<u8> => "ShrAssign",
+        dyn std::ops::Sub<u8, Output = u8> => "Sub",
+        dyn std::ops::SubAssign<u8> => "SubAssign",
+        dyn std::panic::RefUnwindSafe => "RefUnwindSafe",
+        dyn std::panic::UnwindSafe => "UnwindSafe",
+        dyn std::process::Termination => "Termination",
+        dyn std::slice::SliceIndex<u8, Output = u8> => "SliceIndex",
+        dyn std::string::ToString => "ToString",
+        std::alloc::Layout => "Layout",
+        std::alloc::LayoutError => "LayoutError",
+        std::alloc::System => "System",
+        std::any::TypeId => "TypeId",
+        std::array::IntoIter<u8, 0> => "array::IntoIter",
+        std::array::TryFromSliceError => "TryFromSliceError",
+        std::ascii::EscapeDefault => "ascii::EscapeDefault",
+        std::backtrace::Backtrace => "Backtrace",
+        std::backtrace::BacktraceStatus => "BacktraceStatus",
+        std::borrow::Cow<'static, u8> => "Cow",
+        std::boxed::Box<u8> => "Box",
+        std::cell::BorrowError => "BorrowError",
+        std::cell::BorrowMutError => "BorrowMutError",
+        std::cell::Cell<u8> => "Cell",
+        std::cell::LazyCell<u8> => "LazyCell",
+        std::cell::OnceCell<u8> => "OnceCell",
+        std::cell::Ref<'static, u8> => "Ref",
+        std::cell::RefCell<u8> => "RefCell",
+        std::cell::RefMut<'static, u8> => "RefMut",
+        std::cell::UnsafeCell<u8> => "UnsafeCell",
+        std::char::CharTryFromError => "CharTryFromError",
+        std::char::DecodeUtf16<std::iter::Empty<u16>> => "DecodeUtf16",
+        std::char::DecodeUtf16Error => "DecodeUtf16Error",
+        std::char::EscapeDebug => "char::EscapeDebug",
+        std::char::EscapeDefault => "char::EscapeDefault",
+        std::char::EscapeUnicode => "char::EscapeUnicode",
+        std::char::ParseCharError => "ParseCharError",
+        std::char::ToLowercase => "ToLowercase",
+        std::char::ToUppercase => "ToUppercase",
+        std::char::TryFromCharError => "TryFromCharError",
+        std::cmp::Ordering => "cmp::Ordering",
+        std::cmp::Reverse<u8> => "Reverse",
+        std::collections::BTreeMap<u8, u8> => "BTreeMap",
+        std::collections::BTreeSet<u8> => "BTreeSet",
+        std::collections::BinaryHeap<u8> => "BinaryHeap",
+        std::collections::HashMap<u8, u8> => "HashMap",
+        std::collections::HashSet<u8> => "HashSet",
+        std::collections::LinkedList<u8> => "LinkedList",
+        std::collections::TryReserveError => "TryReserveError",
+        std::collections::VecDeque<u8> => "VecDeque",
+        std::collections::binary_heap::BinaryHeap<u8> => "BinaryHeap",
+        std::collections::binary_heap::Drain<'static, u8> => "binary_heap::Drain",
+        std::collections::binary_heap::IntoIter<u8> => "binary_heap::IntoIter",
+        std::collections::binary_heap::Iter<'static, u8> => "binary_heap::Iter",
+        std::collections::binary_heap::PeekMut<'static, u8> => "PeekMut",
+        std::collections::btree_map::BTreeMap<u8, u8> => "BTreeMap",
+        std::collections::btree_map::Entry<'static, u8, u8> => "btree_map::Entry",
+        std::collections::btree_map::ExtractIf<'static, u8, u8, u8, fn()> => "btree_map::ExtractIf",
+        std::collections::btree_map::IntoIter<u8, u8> => "btree_map::IntoIter",
+        std::collections::btree_map::IntoKeys<u8, u8> => "btree_map::IntoKeys",
+        std::collections::btree_map::IntoValues<u8, u8> => "btree_map::IntoValues",
+        std::collections::btree_map::Iter<'static, u8, u8> => "btree_map::Iter",
+        std::collections::btree_map::IterMut<'static, u8, u8> => "btree_map::IterMut",
+        std::collections::btree_map::Keys<'static, u8, u8> => "btree_map::Keys",
+        std::collections::btree_map::OccupiedEntry<'static, u8, u8> => "btree_map::OccupiedEntry",
+        std::collections::btree_map::Range<'static, u8, u8> => "btree_map::Range",
+        std::collections::btree_map::RangeMut<'static, u8, u8> => "RangeMut",
+        std::collections::btree_map::VacantEntry<'static, u8, u8> => "btree_map::VacantEntry",
+        std::collections::btree_map::Values<'static, u8, u8> => "btree_map::Values",
+        std::collections::btree_map::ValuesMut<'static, u8, u8> => "btree_map::ValuesMut",
+        std::collections::btree_set::BTreeSet<u8> => "BTreeSet",
+        std::collections::btree_set::Difference<'static, u8> => "btree_set::Difference",
+        std::collections::btree_set::ExtractIf<'static, u8, u8, fn()> => "btree_set::ExtractIf",
+        std::collections::btree_set::Intersection<'static, u8> => "btree_set::Intersection",
+        std::collections::btree_set::IntoIter<u8> => "btree_set::IntoIter",
+        std::collections::btree_set::Iter<'static, u8> => "btree_set::Iter",
+        std::collections::btree_set::Range<'static, u8> => "btree_set::Range",
+        std::collections::btree_set::SymmetricDifference<'static, u8> => "btree_set::SymmetricDifference",
+        std::collections::btree_set::Union<'static, u8> => "btree_set::Union",
+        std::collections::hash_map::DefaultHasher => "DefaultHasher",
+        std::collections::hash_map::Drain<'static, u8, u8> => "hash_map::Drain",
+        std::collections::hash_map::Entry<'static, u8, u8> => "hash_map::Entry",
+        std::collections::hash_map::ExtractIf<'static, u8, u8, fn()> => "hash_map::ExtractIf",
+        std::collections::hash_map::HashMap<u8, u8> => "HashMap",
+        std::collections::hash_map::IntoIter<u8, u8> => "hash_map::IntoIter",
+        std::collections::hash_map::IntoKeys<u8, u8> => "hash_map::IntoKeys",
+        std::collections::hash_map::IntoValues<u8, u8> => "hash_map::IntoValues",
+        std::collections::hash_map::Iter<'static, u8, u8> => "hash_map::Iter",
+        std::collections::hash_map::IterMut<'static, u8, u8> => "hash_map::IterMut",
+        std::collections::hash_map::Keys<'static, u8, u8> => "hash_map::Keys",
+        std::collections::hash_map::OccupiedEntry<'static, u8, u8> => "hash_map::OccupiedEntry",
+        std::collections::hash_map::RandomState => "RandomState",
+        std::collections::hash_map::VacantEntry<'static, u8, u8> => "hash_map::VacantEntry",
+        std::collections::hash_map::Values<'static, u8, u8> => "hash_map::Values",
+        std::collections::hash_map::ValuesMut<'static, u8, u8> => "hash_map::ValuesMut",
+        std::collections::hash_set::Difference<'static, u8, u8> => "hash_set::Difference",
+        std::collections::hash_set::Drain<'static, u8> => "hash_set::Drain",
+        std::collections::hash_set::ExtractIf<'static, u8, fn()> => "hash_set::ExtractIf",
+        std::collections::hash_set::HashSet<u8> => "HashSet",
+        std::collections::hash_set::Intersection<'static, u8, u8> => "hash_set::Intersection",
+        std::collections::hash_set::IntoIter<u8> => "hash_set::IntoIter",
+        std::collections::hash_set::Iter<'static, u8> => "hash_set::Iter",
+        std::collections::hash_set::SymmetricDifference<'static, u8, u8> => "hash_set::SymmetricDifference",
+        std::collections::hash_set::Union<'static, u8, u8> => "hash_set::Union",
+        std::collections::linked_list::ExtractIf<'static, u8, fn()> => "linked_list::ExtractIf",
+        std::collections::linked_list::IntoIter<u8> => "linked_list::IntoIter",
+        std::collections::linked_list::Iter<'static, u8> => "linked_list::Iter",
+        std::collections::linked_list::IterMut<'static, u8> => "linked_list::IterMut",
+        std::collections::linked_list::LinkedList<u8> => "LinkedList",
+        std::collections::vec_deque::Drain<'static, u8> => "vec_deque::Drain",
+        std::collections::vec_deque::IntoIter<u8> => "vec_deque::IntoIter",
+        std::collections::vec_deque::Iter<'static, u8> => "vec_deque::Iter",
+        std::collections::vec_deque::IterMut<'static, u8> => "vec_deque::IterMut",
+        std::collections::vec_deque::VecDeque<u8> => "VecDeque",
+        std::convert::Infallible => "Infallible",
+        std::env::Args => "Args",
+        std::env::ArgsOs => "ArgsOs",
+        std::env::JoinPathsError => "JoinPathsError",
+        std::env::SplitPaths<'static> => "SplitPaths",
+        std::env::VarError => "VarError",
+        std::env::Vars => "Vars",
+        std::env::VarsOs => "VarsOs",
+        std::ffi::CStr => "CStr",
+        std::ffi::CString => "CString",
+        std::ffi::FromBytesUntilNulError => "FromBytesUntilNulError",
+        std::ffi::FromBytesWithNulError => "FromBytesWithNulError",
+        std::ffi::FromVecWithNulError => "FromVecWithNulError",
+        std::ffi::IntoStringError => "IntoStringError",
+        std::ffi::NulError => "NulError",
+        std::ffi::OsStr => "OsStr",
+        std::ffi::OsString => "OsString",
+        std::ffi::c_str::CStr => "CStr",
+        std::ffi::c_str::CString => "CString",
+        std::ffi::c_str::FromBytesUntilNulError => "FromBytesUntilNulError",
+        std::ffi::c_str::FromBytesWithNulError => "FromBytesWithNulError",
+        std::ffi::c_str::FromVecWithNulError => "FromVecWithNulError",
+        std::ffi::c_str::IntoStringError => "IntoStringError",
+        std::ffi::c_str::NulError => "NulError",
+        std::ffi::c_void => "c_void",
+        std::ffi::os_str::Display<'static> => "os_str::Display",
+        std::ffi::os_str::OsStr => "OsStr",
+        std::ffi::os_str::OsString => "OsString",
+        std::fmt::Alignment => "Alignment",
+        std::fmt::Arguments<'static> => "Arguments",
+        std::fmt::DebugList<'static, 'static> => "DebugList",
+        std::fmt::DebugMap<'static, 'static> => "DebugMap",
+        std::fmt::DebugSet<'static, 'static> => "DebugSet",
+        std::fmt::DebugStruct<'static, 'static> => "DebugStruct",
+        std::fmt::DebugTuple<'static, 'static> => "DebugTuple",
+        std::fmt::Error => "fmt::Error",
+        std::fmt::Formatter<'static> => "Formatter",
+        std::fmt::FromFn<fn()> => "fmt::FromFn",
+        std::fs::DirBuilder => "DirBuilder",
+        std::fs::DirEntry => "DirEntry",
+        std::fs::File => "File",
+        std::fs::FileTimes => "FileTimes",
+        std::fs::FileType => "FileType",
+        std::fs::Metadata => "Metadata",
+        std::fs::OpenOptions => "OpenOptions",
+        std::fs::Permissions => "Permissions",
+        std::fs::ReadDir => "ReadDir",
+        std::fs::TryLockError => "fs::TryLockError",
+        std::future::Pending<u8> => "Pending",
+        std::future::PollFn<fn()> => "PollFn",
+        std::future::Ready<u8> => "Ready",
+        std::hash::BuildHasherDefault<u8> => "BuildHasherDefault",
+        std::hash::DefaultHasher => "DefaultHasher",
+        std::hash::RandomState => "RandomState",
+        std::hash::SipHasher => "SipHasher",
+        std::io::BufReader<u8> => "BufReader",
+        std::io::BufWriter<std::io::Empty> => "BufWriter",
+        std::io::Bytes<u8> => "io::Bytes",
+        std::io::Chain<u8, u8> => "io::Chain",
+        std::io::Cursor<u8> => "Cursor",
+        std::io::Empty => "io::Empty",
+        std::io::Error => "io::Error",
+        std::io::ErrorKind => "ErrorKind",
+        std::io::IntoInnerError<u8> => "IntoInnerError",
+        std::io::IoSlice<'static> => "IoSlice",
+        std::io::IoSliceMut<'static> => "IoSliceMut",
+        std::io::LineWriter<std::io::Empty> => "LineWriter",
+        std::io::Lines<u8> => "io::Lines",
+        std::io::PipeReader => "PipeReader",
+        std::io::PipeWriter => "PipeWriter",
+        std::io::Repeat => "io::Repeat",
+        std::io::SeekFrom => "SeekFrom",
+        std::io::Sink => "Sink",
+        std::io::Split<u8> => "io::Split",
+        std::io::Stderr => "Stderr",
+        std::io::StderrLock<'static> => "StderrLock",
+        std::io::Stdin => "Stdin",
+        std::io::StdinLock<'static> => "StdinLock",
+        std::io::Stdout => "Stdout",
+        std::io::StdoutLock<'static> => "StdoutLock",
+        std::io::Take<u8> => "io::Take",
+        std::io::WriterPanicked => "WriterPanicked",
+        std::iter::Chain<u8, u8> => "iter::Chain",
+        std::iter::Cloned<u8> => "Cloned",
+        std::iter::Copied<u8> => "Copied",
+        std::iter::Cycle<u8> => "Cycle",
+        std::iter::Empty<u8> => "iter::Empty",
+        std::iter::Enumerate<u8> => "Enumerate",
+        std::iter::Filter<u8, fn()> => "Filter",
+        std::iter::FilterMap<u8, fn()> => "FilterMap",
+        std::iter::FlatMap<u8, std::iter::Empty<u8>, fn()> => "FlatMap",
+        std::iter::Flatten<std::iter::Empty<Vec<u8>>> => "Flatten",
+        std::iter::FromFn<fn()> => "iter::FromFn",
+        std::iter::Fuse<u8> => "Fuse",
+        std::iter::Inspect<u8, fn()> => "Inspect",
+        std::iter::Map<u8, fn()> => "Map",
+        std::iter::MapWhile<u8, fn()> => "MapWhile",
+        std::iter::Once<u8> => "iter::Once",
+        std::iter::OnceWith<fn()> => "OnceWith",
+        std::iter::Peekable<std::iter::Empty<u8>> => "Peekable",
+        std::iter::Repeat<u8> => "iter::Repeat",
+        std::iter::RepeatN<u8> => "RepeatN",
+        std::iter::RepeatWith<fn()> => "RepeatWith",
+        std::iter::Rev<u8> => "Rev",
+        std::iter::Scan<u8, u8, fn()> => "Scan",
+        std::iter::Skip<u8> => "Skip",
+        std::iter::SkipWhile<u8, fn()> => "SkipWhile",
+        std::iter::StepBy<u8> => "StepBy",
+        std::iter::Successors<u8, fn()> => "Successors",
+        std::iter::Take<u8> => "iter::Take",
+        std::iter::TakeWhile<u8, fn()> => "TakeWhile",
+        std::iter::Zip<u8, u8> => "Zip",
+        std::marker::PhantomData<u8> => "PhantomData",
+        std::marker::PhantomPinned => "PhantomPinned",
+        std::mem::Discriminant<u8> => "Discriminant",
+        std::mem::ManuallyDrop<u8> => "ManuallyDrop",
+        std::mem::MaybeUninit<u8> => "MaybeUninit",
+        std::net::AddrParseError => "AddrParseError",
+        std::net::Incoming<'static> => "net::Incoming",
+        std::net::IpAddr => "IpAddr",
+        std::net::Ipv4Addr => "Ipv4Addr",
+        std::net::Ipv6Addr => "Ipv6Addr",
+        std::net::Shutdown => "Shutdown",
+        std::net::SocketAddr => "net::SocketAddr",
+        std::net::SocketAddrV4 => "SocketAddrV4",
+        std::net::SocketAddrV6 => "SocketAddrV6",
+        std::net::TcpListener => "TcpListener",
+        std::net::TcpStream => "TcpStream",
+        std::net::UdpSocket => "UdpSocket",
+        std::num::FpCategory => "FpCategory",
+        std::num::IntErrorKind => "IntErrorKind",
+        std::num::NonZero<u8> => "NonZero",
+        std::num::ParseFloatError => "ParseFloatError",
+        std::num::ParseIntError => "ParseIntError",
+        std::num::Saturating<u8> => "Saturating",
+        std::num::TryFromIntError => "TryFromIntError",
+        std::num::Wrapping<u8> => "Wrapping",
+        std::ops::Bound<u8> => "Bound",
+        std::ops::ControlFlow<u8> => "ControlFlow",
+        std::ops::Range<u8> => "ops::Range",
+        std::ops::RangeFrom<u8> => "RangeFrom",
+        std::ops::RangeFull => "RangeFull",
+        std::ops::RangeInclusive<u8> => "ops::RangeInclusive",
+        std::ops::RangeTo<u8> => "RangeTo",
+        std::ops::RangeToInclusive<u8> => "RangeToInclusive",
+        std::option::IntoIter<u8> => "option::IntoIter",
+        std::option::Iter<'static, u8> => "option::Iter",
+        std::option::IterMut<'static, u8> => "option::IterMut",
+        std::option::Option<u8> => "Option",
+        std::panic::AssertUnwindSafe<u8> => "AssertUnwindSafe",
+        std::panic::Location<'static> => "Location",
+        std::panic::PanicHookInfo<'static> => "PanicHookInfo",
+        std::path::Ancestors<'static> => "Ancestors",
+        std::path::Component<'static> => "Component",
+        std::path::Components<'static> => "Components",
+        std::path::Display<'static> => "path::Display",
+        std::path::Iter<'static> => "path::Iter",
+        std::path::Path => "Path",
+        std::path::PathBuf => "PathBuf",
+        std::path::Prefix<'static> => "Prefix",
+        std::path::PrefixComponent<'static> => "PrefixComponent",
+        std::path::StripPrefixError => "StripPrefixError",
+        std::pin::Pin<u8> => "Pin",
+        std::process::Child => "Child",
+        std::process::ChildStderr => "ChildStderr",
+        std::process::ChildStdin => "ChildStdin",
+        std::process::ChildStdout => "ChildStdout",
+        std::process::Command => "Command",
+        std::process::CommandArgs<'static> => "CommandArgs",
+        std::process::CommandEnvs<'static> => "CommandEnvs",
+        std::process::ExitCode => "ExitCode",
+        std::process::ExitStatus => "ExitStatus",
+        std::process::Output => "Output",
+        std::process::Stdio => "Stdio",
+        std::ptr::NonNull<u8> => "NonNull",
+        std::range::RangeInclusive<u8> => "range::RangeInclusive",
+        std::range::RangeInclusiveIter<u8> => "RangeInclusiveIter",
+        std::rc::Rc<u8> => "Rc",
+        std::rc::Weak<u8> => "rc::Weak",
+        std::result::IntoIter<u8> => "result::IntoIter",
+        std::result::Iter<'static, u8> => "result::Iter",
+        std::result::IterMut<'static, u8> => "result::IterMut",
+        std::result::Result<u8, u8> => "Result",
+        std::slice::ArrayWindows<'static, u8, 0> => "ArrayWindows",
+        std::slice::ChunkBy<'static, u8, fn()> => "ChunkBy",
+        std::slice::ChunkByMut<'static, u8, fn()> => "ChunkByMut",
+        std::slice::Chunks<'static, u8> => "Chunks",
+        std::slice::ChunksExact<'static, u8> => "ChunksExact",
+        std::slice::ChunksExactMut<'static, u8> => "ChunksExactMut",
+        std::slice::ChunksMut<'static, u8> => "ChunksMut",
+        std::slice::EscapeAscii<'static> => "EscapeAscii",
+        std::slice::GetDisjointMutError => "GetDisjointMutError",
+        std::slice::Iter<'static, u8> => "slice::Iter",
+        std::slice::IterMut<'static, u8> => "slice::IterMut",
+        std::slice::RChunks<'static, u8> => "RChunks",
+        std::slice::RChunksExact<'static, u8> => "RChunksExact",
+        std::slice::RChunksExactMut<'static, u8> => "RChunksExactMut",
+        std::slice::RChunksMut<'static, u8> => "RChunksMut",
+        std::slice::RSplit<'static, u8, fn(&u8) -> bool> => "slice::RSplit",
+        std::slice::RSplitMut<'static, u8, fn(&u8) -> bool> => "RSplitMut",
+        std::slice::RSplitN<'static, u8, fn(&u8) -> bool> => "slice::RSplitN",
+        std::slice::RSplitNMut<'static, u8, fn(&u8) -> bool> => "RSplitNMut",
+        std::slice::Split<'static, u8, fn(&u8) -> bool> => "slice::Split",
+        std::slice::SplitInclusive<'static, u8, fn(&u8) -> bool> => "slice::SplitInclusive",
+        std::slice::SplitInclusiveMut<'static, u8, fn(&u8) -> bool> => "SplitInclusiveMut",
+        std::slice::SplitMut<'static, u8, fn(&u8) -> bool> => "SplitMut",
+        std::slice::SplitN<'static, u8, fn(&u8) -> bool> => "slice::SplitN",
+        std::slice::SplitNMut<'static, u8, fn(&u8) -> bool> => "SplitNMut",
+        std::slice::Windows<'static, u8> => "Windows",
+        std::str::Bytes<'static> => "str::Bytes",
+        std::str::CharIndices<'static> => "CharIndices",
+        std::str::Chars<'static> => "Chars",
+        std::str::EncodeUtf16<'static> => "EncodeUtf16",
+        std::str::EscapeDebug<'static> => "str::EscapeDebug",
+        std::str::EscapeDefault<'static> => "str::EscapeDefault",
+        std::str::EscapeUnicode<'static> => "str::EscapeUnicode",
+        std::str::Lines<'static> => "str::Lines",
+        std::str::LinesAny<'static> => "LinesAny",
+        std::str::MatchIndices<'static, char> => "MatchIndices",
+        std::str::Matches<'static, char> => "Matches",
+        std::str::ParseBoolError => "ParseBoolError",
+        std::str::RMatchIndices<'static, char> => "RMatchIndices",
+        std::str::RMatches<'static, char> => "RMatches",
+        std::str::RSplit<'static, char> => "str::RSplit",
+        std::str::RSplitN<'static, char> => "str::RSplitN",
+        std::str::RSplitTerminator<'static, char> => "RSplitTerminator",
+        std::str::Split<'static, char> => "str::Split",
+        std::str::SplitAsciiWhitespace<'static> => "SplitAsciiWhitespace",
+        std::str::SplitInclusive<'static, char> => "str::SplitInclusive",
+        std::str::SplitN<'static, char> => "str::SplitN",
+        std::str::SplitTerminator<'static, char> => "SplitTerminator",
+        std::str::SplitWhitespace<'static> => "SplitWhitespace",
+        std::str::Utf8Chunk<'static> => "Utf8Chunk",
+        std::str::Utf8Chunks<'static> => "Utf8Chunks",
+        std::str::Utf8Error => "Utf8Error",
+        std::string::Drain<'static> => "string::Drain",
+        std::string::FromUtf16Error => "FromUtf16Error",
+        std::string::FromUtf8Error => "FromUtf8Error",
+        std::string::String => "String",
+        std::sync::Arc<u8> => "Arc",
+        std::sync::Barrier => "Barrier",
+        std::sync::BarrierWaitResult => "BarrierWaitResult",
+        std::sync::Condvar => "Condvar",
+        std::sync::LazyLock<u8> => "LazyLock",
+        std::sync::Mutex<u8> => "Mutex",
+        std::sync::MutexGuard<'static, u8> => "MutexGuard",
+        std::sync::Once => "sync::Once",
+        std::sync::OnceLock<u8> => "OnceLock",
+        std::sync::OnceState => "OnceState",
+        std::sync::PoisonError<u8> => "PoisonError",
+        std::sync::RwLock<u8> => "RwLock",
+        std::sync::RwLockReadGuard<'static, u8> => "RwLockReadGuard",
+        std::sync::RwLockWriteGuard<'static, u8> => "RwLockWriteGuard",
+        std::sync::TryLockError<u8> => "sync::TryLockError",
+        std::sync::WaitTimeoutResult => "WaitTimeoutResult",
+        std::sync::Weak<u8> => "sync::Weak",
+        std::sync::atomic::AtomicBool => "AtomicBool",
+        std::sync::atomic::AtomicI16 => "AtomicI16",
+        std::sync::atomic::AtomicI32 => "AtomicI32",
+        std::sync::atomic::AtomicI64 => "AtomicI64",
+        std::sync::atomic::AtomicI8 => "AtomicI8",
+        std::sync::atomic::AtomicIsize => "AtomicIsize",
+        std::sync::atomic::AtomicPtr<u8> => "AtomicPtr",
+        std::sync::atomic::AtomicU16 => "AtomicU16",
+        std::sync::atomic::AtomicU32 => "AtomicU32",
+        std::sync::atomic::AtomicU64 => "AtomicU64",
+        std::sync::atomic::AtomicU8 => "AtomicU8",
+        std::sync::atomic::AtomicUsize => "AtomicUsize",
+        std::sync::atomic::Ordering => "atomic::Ordering",
+        std::sync::mpsc::IntoIter<u8> => "mpsc::IntoIter",
+        std::sync::mpsc::Iter<'static, u8> => "mpsc::Iter",
+        std::sync::mpsc::Receiver<u8> => "Receiver",
+        std::sync::mpsc::RecvError => "RecvError",
+        std::sync::mpsc::RecvTimeoutError => "RecvTimeoutError",
+        std::sync::mpsc::SendError<u8> => "SendError",
+        std::sync::mpsc::Sender<u8> => "Sender",
+        std::sync::mpsc::SyncSender<u8> => "SyncSender",
+        std::sync::mpsc::TryIter<'static, u8> => "TryIter",
+        std::sync::mpsc::TryRecvError => "TryRecvError",
+        std::sync::mpsc::TrySendError<u8> => "TrySendError",
+        std::task::Context<'static> => "Context",
+        std::task::Poll<u8> => "Poll",
+        std::task::RawWaker => "RawWaker",
+        std::task::RawWakerVTable => "RawWakerVTable",
+        std::task::Waker => "Waker",
+        std::thread::AccessError => "AccessError",
+        std::thread::Builder => "Builder",
+        std::thread::JoinHandle<u8> => "JoinHandle",
+        std::thread::LocalKey<u8> => "LocalKey",
+        std::thread::Scope<'static, 'static> => "Scope",
+        std::thread::ScopedJoinHandle<'static, u8> => "ScopedJoinHandle",
+        std::thread::Thread => "Thread",
+        std::thread::ThreadId => "ThreadId",
+        std::time::Duration => "Duration",
+        std::time::Instant => "Instant",
+        std::time::SystemTime => "SystemTime",
+        std::time::SystemTimeError => "SystemTimeError",
+        std::time::TryFromFloatSecsError => "TryFromFloatSecsError",
+        std::vec::Drain<'static, u8> => "vec::Drain",
+        std::vec::ExtractIf<'static, u8, fn()> => "vec::ExtractIf",
+        std::vec::IntoIter<u8> => "vec::IntoIter",
+        std::vec::Splice<'static, std::iter::Empty<u8>> => "Splice",
+        std::vec::Vec<u8> => "Vec",
+    }
+    #[cfg(unix)]
+    check! {
+        dyn std::os::fd::AsFd => "AsFd",
+        dyn std::os::fd::AsRawFd => "AsRawFd",
+        dyn std::os::fd::IntoRawFd => "IntoRawFd",
+        dyn std::os::unix::fs::DirEntryExt => "DirEntryExt",
+        dyn std::os::unix::fs::FileExt => "FileExt",
+        dyn std::os::unix::fs::FileTypeExt => "FileTypeExt",
+        dyn std::os::unix::fs::MetadataExt => "unix::fs::MetadataExt",
+        dyn std::os::unix::thread::JoinHandleExt => "JoinHandleExt",
+        std::os::fd::BorrowedFd<'static> => "BorrowedFd",
+        std::os::fd::OwnedFd => "OwnedFd",
+        std::os::unix::net::Incoming<'static> => "unix::net::Incoming",
+        std::os::unix::net::SocketAddr => "unix::net::SocketAddr",
+        std::os::unix::net::UnixDatagram => "UnixDatagram",
+        std::os::unix::net::UnixListener => "UnixListener",
+        std::os::unix::net::UnixStream => "UnixStream",
+    }
+    #[cfg(target_os = "linux")]
+    check! {
+        dyn std::os::linux::fs::MetadataExt => "linux::fs::MetadataExt",
+        dyn std::os::linux::net::TcpStreamExt => "TcpStreamExt",
+        std::os::linux::raw::stat => "stat",
+    }
+}
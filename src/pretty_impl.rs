@@ -1,17 +1,222 @@
+use std::borrow::Cow;
+use crate::style::TyNameStyle;
+
 /// Strips std path-noise from [`type_name()`](std::any::type_name).
 ///
-/// You can customize the behavior by using a [cargo patch].
+/// Every path in the name is prettified, including those nested inside generic arguments, tuples,
+/// arrays, slices, references and `dyn` bounds. The input is borrowed back if nothing changed, or
+/// if it isn't a valid [`TypeName`](crate::TypeName).
+///
+/// The paths are shortened according to the current [`PrettyRules`](crate::PrettyRules).
+/// You can also change the builtin rules by using a [cargo patch].
 ///
 /// [cargo patch]: https://doc.rust-lang.org/cargo/reference/overriding-dependencies.html?#the-patch-section
-pub fn pretty(name: &str) -> &str {
-    // FIXME(rust): This can be const once type_name is.
-    let pretty = include!("pretty.expr.rs");
-    for &(bad, good) in pretty {
-        if let Some(name) = name.strip_prefix(bad) {
-            if name.starts_with(good) {
-                return name;
-            }
+pub fn pretty(name: &str) -> Cow<'_, str> {
+    TyNameStyle::Pretty.render(name)
+}
+
+/// Rewrites the std, core & alloc paths in a [`type_name()`](std::any::type_name) to their public
+/// `std::` re-exports, so that the name can be pasted into code or searched for in the docs.
+///
+/// ```
+/// # use ezty::canonical;
+/// assert_eq!(
+///     canonical("alloc::collections::btree::map::BTreeMap<u8, core::cell::RefCell<u8>>"),
+///     "std::collections::BTreeMap<u8, std::cell::RefCell<u8>>",
+/// );
+/// ```
+///
+/// Unlike [`pretty`], this ignores the current [`PrettyRules`](crate::PrettyRules).
+pub fn canonical(name: &str) -> Cow<'_, str> {
+    TyNameStyle::Canonical.render(name)
+}
+
+/// The [`pretty`] name of a function's signature, eg `fn(Vec<u8>) -> Option<String>`.
+///
+/// The [`type_name()`](std::any::type_name) of a function item is just its path, which says
+/// nothing about what the function takes or returns; this names the equivalent function pointer.
+/// ```
+/// fn parse(_: Vec<u8>) -> Option<String> { None }
+/// assert_eq!(ezty::fn_signature(&parse), "fn(Vec<u8>) -> Option<String>");
+/// ```
+pub fn fn_signature<F: FnSignature<Args>, Args>(_: &F) -> &'static str {
+    TyNameStyle::Pretty.render_static(std::any::type_name::<F::Ptr>())
+}
+
+/// Functions & closures that can be described by a function pointer. See [`fn_signature`].
+pub trait FnSignature<Args> {
+    /// The function pointer type, eg `fn(A, B) -> R`.
+    type Ptr: ?Sized;
+}
+
+macro_rules! fn_signature {
+    ($($arg:ident),*) => {
+        impl<F, R, $($arg),*> FnSignature<($($arg,)*)> for F
+        where
+            F: Fn($($arg),*) -> R,
+        {
+            type Ptr = fn($($arg),*) -> R;
+        }
+    };
+}
+fn_signature!();
+fn_signature!(A);
+fn_signature!(A, B);
+fn_signature!(A, B, C);
+fn_signature!(A, B, C, D);
+fn_signature!(A, B, C, D, E);
+fn_signature!(A, B, C, D, E, G);
+fn_signature!(A, B, C, D, E, G, H);
+fn_signature!(A, B, C, D, E, G, H, I);
+
+#[cfg(test)]
+mod tests {
+    use super::{canonical, fn_signature, pretty};
+
+    #[test]
+    fn nested() {
+        assert_eq!(pretty("alloc::vec::Vec<u8>"), "Vec<u8>");
+        assert_eq!(
+            pretty("core::option::Option<alloc::boxed::Box<alloc::vec::Vec<u8>>>"),
+            "Option<Box<Vec<u8>>>",
+        );
+        assert_eq!(
+            pretty("std::collections::hash::map::HashMap<u8, alloc::vec::Vec<u8>>"),
+            "HashMap<u8, Vec<u8>>",
+        );
+    }
+
+    #[test]
+    fn structural() {
+        assert_eq!(pretty("(alloc::vec::Vec<u8>, core::cell::Cell<u8>)"), "(Vec<u8>, Cell<u8>)");
+        assert_eq!(pretty("[alloc::vec::Vec<u8>; 3]"), "[Vec<u8>; 3]");
+        assert_eq!(pretty("&mut [core::option::Option<u8>]"), "&mut [Option<u8>]");
+        assert_eq!(pretty("&'_ alloc::vec::Vec<u8>"), "&'_ Vec<u8>");
+        assert_eq!(
+            pretty("alloc::boxed::Box<dyn core::ops::function::Fn(alloc::vec::Vec<u8>)>"),
+            "Box<dyn Fn(Vec<u8>)>",
+        );
+        assert_eq!(pretty("fn(alloc::vec::Vec<u8>) -> core::option::Option<u8>"), "fn(Vec<u8>) -> Option<u8>");
+    }
+
+    #[test]
+    fn closures() {
+        assert_eq!(pretty("my_app::main::{{closure}}"), "closure in main");
+        assert_eq!(pretty("my_app::serve::{async_block#0}"), "async block in serve");
+        assert_eq!(pretty("my_app::serve::{async_fn#0}"), "async fn serve");
+        assert_eq!(
+            pretty("core::option::Option<my_app::Job<alloc::string::String>::run::{{closure}}>"),
+            "Option<closure in run>",
+        );
+        assert_eq!(canonical("my_app::main::{{closure}}"), "my_app::main::{{closure}}");
+        fn ty_of<T: 'static>(_: &T) -> crate::Ty { crate::Ty::of::<T>() }
+        assert_eq!(ty_of(&|_: u8| ()).name(), "closure in closures");
+    }
+
+    #[test]
+    fn fn_items() {
+        fn parse(_: Vec<u8>) -> Option<String> { None }
+        fn nothing() {}
+        assert_eq!(fn_signature(&parse), "fn(Vec<u8>) -> Option<String>");
+        assert_eq!(fn_signature(&nothing), "fn()");
+        assert_eq!(fn_signature(&|a: u8, b: &str| a as usize + b.len()), "fn(u8, &str) -> usize");
+        assert_eq!(
+            pretty("fn(alloc::vec::Vec<u8>) -> core::option::Option<alloc::string::String>"),
+            "fn(Vec<u8>) -> Option<String>",
+        );
+    }
+
+    #[test]
+    fn common_std_types() {
+        use std::borrow::Cow;
+        use std::marker::PhantomData;
+        use std::num::NonZeroU32;
+        use std::path::PathBuf;
+        use std::pin::Pin;
+        use std::rc::{Rc, Weak};
+        use std::sync::{mpsc, Mutex};
+        use std::time::Duration;
+        use crate::Ty;
+        assert_eq!(Ty::of::<Option<String>>().name(), "Option<String>");
+        assert_eq!(Ty::of::<Rc<Mutex<PathBuf>>>().name(), "Rc<Mutex<PathBuf>>");
+        assert_eq!(Ty::of::<Pin<Box<PhantomData<Duration>>>>().name(), "Pin<Box<PhantomData<Duration>>>");
+        assert_eq!(Ty::of::<Weak<NonZeroU32>>().name(), "rc::Weak<NonZero<u32>>");
+        assert_eq!(Ty::of::<Cow<'static, str>>().name(), "Cow<'_, str>");
+        assert_eq!(Ty::of::<mpsc::Sender<std::io::Error>>().name(), "Sender<io::Error>");
+        assert_eq!(Ty::of::<std::ops::Range<u8>>().name(), "ops::Range<u8>");
+    }
+
+    #[test]
+    #[allow(deprecated, dyn_drop)]
+    fn every_std_type() {
+        use crate::{Ty, TypeName, Bound};
+        macro_rules! check {
+            ($($ty:ty => $name:literal,)*) => {$({
+                let ty = Ty::of::<$ty>();
+                let outer = match TypeName::parse(&ty.name()).unwrap() {
+                    TypeName::Dyn(bounds) => match bounds.into_iter().next() {
+                        Some(Bound::Trait { path, .. }) => path,
+                        _ => panic!(),
+                    },
+                    TypeName::Path(path) => path,
+                    _ => panic!(),
+                };
+                let outer = outer.segments.iter().map(|s| &s.name[..]).collect::<Vec<_>>().join("::");
+                assert_eq!(outer, $name, "{}", ty.full_name());
+            })*};
         }
+        include!("pretty.tys.rs");
+    }
+
+    #[test]
+    fn canonicalized() {
+        assert_eq!(
+            canonical("std::collections::hash::map::HashMap<alloc::string::String, alloc::sync::Arc<u8>>"),
+            "std::collections::HashMap<std::string::String, std::sync::Arc<u8>>",
+        );
+        assert_eq!(
+            canonical("std::collections::hash::map::Iter<'_, u8, u8>"),
+            "std::collections::hash_map::Iter<'_, u8, u8>",
+        );
+        assert_eq!(canonical("std::sync::poison::mutex::Mutex<u8>"), "std::sync::Mutex<u8>");
+        assert_eq!(canonical("core::num::nonzero::NonZero<u8>"), "std::num::NonZero<u8>");
+        assert_eq!(canonical("std::sync::mutex::Mutex<u8>"), "std::sync::Mutex<u8>");
+        assert_eq!(canonical("core::num::nonzero::NonZeroU32"), "std::num::NonZeroU32");
+        assert_eq!(
+            canonical("dyn core::fmt::Debug + core::marker::Send"),
+            "dyn std::fmt::Debug + std::marker::Send",
+        );
+        assert_eq!(canonical("my::Vec<u8>"), "my::Vec<u8>");
+    }
+
+    #[test]
+    fn canonical_table() {
+        let canonical: &[(&str, &str)] = include!("canonical.expr.rs");
+        for &(from, to) in canonical {
+            assert!(to.starts_with("std::"), "{from} -> {to}");
+            assert_ne!(from, to);
+            assert_eq!(super::canonical(from), to);
+        }
+    }
+
+    #[test]
+    fn untouched() {
+        use std::borrow::Cow;
+        assert!(matches!(pretty("my::Vec<u8>"), Cow::Borrowed("my::Vec<u8>")));
+        assert!(matches!(pretty("not a type"), Cow::Borrowed("not a type")));
+        assert_eq!(pretty("alloc::vec::VecDeque"), "alloc::vec::VecDeque");
+        assert_eq!(pretty("core::cell::RefCell<u8>"), "RefCell<u8>");
+    }
+
+    #[test]
+    fn older_paths() {
+        assert_eq!(pretty("std::sync::mutex::Mutex<alloc::vec::Vec<u8>>"), "Mutex<Vec<u8>>");
+        assert_eq!(pretty("std::sync::mutex::MutexGuard<'_, u8>"), "MutexGuard<'_, u8>");
+        assert_eq!(pretty("std::sync::condvar::Condvar"), "Condvar");
+        assert_eq!(pretty("std::sync::rwlock::RwLock<u8>"), "RwLock<u8>");
+        assert_eq!(pretty("std::sync::rwlock::RwLockWriteGuard<'_, u8>"), "RwLockWriteGuard<'_, u8>");
+        assert_eq!(pretty("core::num::nonzero::NonZeroU32"), "NonZeroU32");
+        assert_eq!(pretty("alloc::sync::Arc<core::num::nonzero::NonZeroI64>"), "Arc<NonZeroI64>");
+        assert_eq!(pretty("alloc::boxed::Box<dyn std::error::Error>"), "Box<dyn error::Error>");
     }
-    name
 }
@@ -0,0 +1,302 @@
+use std::alloc::Layout;
+use std::any::{Any, TypeId as StdTypeId};
+use std::collections::{BTreeMap, HashMap};
+use std::ops::Bound;
+use std::sync::{OnceLock, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use crate::intern::intern;
+use crate::rules::PrettyRules;
+use crate::style::TyNameStyle;
+use crate::{LTy, Ty};
+
+/// A set of types that can be looked up by their [`TypeId`](StdTypeId) or their names.
+///
+/// There is a [`global`](Self::global) registry, which starts out with common std types, and
+/// more can be made with [`new`](Self::new). Types are added with [`register`](Self::register)
+/// or [`register_types!`](crate::register_types).
+/// ```
+/// # use ezty::{register_types, Ty, TyRegistry};
+/// struct Config;
+/// register_types!(Config, Vec<Config>);
+///
+/// let registry = TyRegistry::global();
+/// let payload: Box<dyn std::any::Any> = Box::new(Vec::<Config>::new());
+/// assert_eq!(registry.of_any(&*payload), Some(Ty::of::<Vec<Config>>()));
+/// assert_eq!(registry.by_name("alloc::string::String"), Some(Ty::of::<String>()));
+/// assert_eq!(registry.by_pretty_name("Vec<u8>"), [Ty::of::<Vec<u8>>()]);
+/// ```
+#[derive(Debug, Default)]
+pub struct TyRegistry {
+    entries: RwLock<Entries>,
+}
+
+#[derive(Debug, Default)]
+struct Entries {
+    /// Every type, with its layout if it was registered as an [`LTy`].
+    layouts: HashMap<Ty, Option<Layout>>,
+    by_id: HashMap<StdTypeId, Ty>,
+    /// The types with each full name. Different versions of a crate can have types with the
+    /// same name.
+    by_name: BTreeMap<&'static str, Vec<Ty>>,
+    /// The types with each [canonical](crate::canonical) name.
+    by_canonical: BTreeMap<&'static str, Vec<Ty>>,
+    /// The types with each pretty name, under the builtin rules.
+    by_pretty: BTreeMap<&'static str, Vec<Ty>>,
+}
+
+impl TyRegistry {
+    /// An empty registry.
+    pub fn new() -> Self {
+        TyRegistry::default()
+    }
+
+    /// A registry of common std types: primitives, strings, and some containers, smart pointers
+    /// & panic payloads.
+    pub fn with_std() -> Self {
+        let registry = TyRegistry::new();
+        registry.register_std();
+        registry
+    }
+
+    /// The registry for every thread, which starts out [`with_std`](Self::with_std) & the
+    /// [`linked`](Self::linked) types.
+    pub fn global() -> &'static TyRegistry {
+        static GLOBAL: OnceLock<TyRegistry> = OnceLock::new();
+        GLOBAL.get_or_init(|| {
+            let registry = TyRegistry::with_std();
+            let linked = TyRegistry::linked().read();
+            for (&ty, &layout) in &linked.layouts {
+                registry.insert(ty, layout);
+            }
+            registry
+        })
+    }
+
+    /// Adds `T`, with its layout. Returns `false` if it was already here.
+    pub fn register<T: 'static>(&self) -> bool {
+        self.register_lty(LTy::of::<T>())
+    }
+
+    /// Adds a type, with its layout. Returns `false` if it was already here.
+    pub fn register_lty(&self, lty: LTy) -> bool {
+        self.insert(lty.ty(), Some(lty.layout()))
+    }
+
+    /// Adds a type, which may be unsized. Returns `false` if it was already here.
+    pub fn register_ty(&self, ty: Ty) -> bool {
+        self.insert(ty, None)
+    }
+
+    fn insert(&self, ty: Ty, layout: Option<Layout>) -> bool {
+        let mut entries = self.write();
+        if let Some(known) = entries.layouts.get_mut(&ty) {
+            *known = known.or(layout);
+            return false;
+        }
+        entries.layouts.insert(ty, layout);
+        if let Some(id) = ty.id().std() {
+            entries.by_id.insert(id, ty);
+        }
+        entries.by_name.entry(ty.full_name()).or_default().push(ty);
+        entries.by_canonical.entry(TyNameStyle::Canonical.render_static(ty.full_name())).or_default().push(ty);
+        entries.by_pretty.entry(builtin_pretty_name(ty)).or_default().push(ty);
+        true
+    }
+
+    /// The type with this [`TypeId`](StdTypeId).
+    pub fn by_type_id(&self, id: StdTypeId) -> Option<Ty> {
+        self.read().by_id.get(&id).copied()
+    }
+
+    /// The type of `value`, eg of a panic payload.
+    pub fn of_any(&self, value: &dyn Any) -> Option<Ty> {
+        self.by_type_id(value.type_id())
+    }
+
+    /// The type with this [`type_name()`](std::any::type_name).
+    pub fn by_name(&self, name: &str) -> Option<Ty> {
+        self.read().by_name.get(name)?.first().copied()
+    }
+
+    /// The type with this [`canonical`](crate::canonical) name, eg `std::vec::Vec<u8>`.
+    pub fn by_canonical_name(&self, name: &str) -> Option<Ty> {
+        self.read().by_canonical.get(name)?.first().copied()
+    }
+
+    /// The types with this [`pretty`](crate::pretty) name, under the
+    /// [builtin](PrettyRules::builtin) rules, whichever rules are in effect. There can be more
+    /// than one, eg `Error` for errors from different crates.
+    pub fn by_pretty_name(&self, name: &str) -> Vec<Ty> {
+        self.read().by_pretty.get(name).cloned().unwrap_or_default()
+    }
+
+    /// The types whose full or pretty names start with `prefix`, eg `"alloc::vec::"` or
+    /// `"Vec<"`, in order of their full names.
+    pub fn by_prefix(&self, prefix: &str) -> Vec<Ty> {
+        let entries = self.read();
+        let starting_with = |names: &BTreeMap<&'static str, Vec<Ty>>| {
+            names
+                .range::<str, _>((Bound::Included(prefix), Bound::Unbounded))
+                .take_while(|(name, _)| name.starts_with(prefix))
+                .flat_map(|(_, tys)| tys.clone())
+                .collect::<Vec<_>>()
+        };
+        let mut tys = starting_with(&entries.by_name);
+        tys.extend(starting_with(&entries.by_pretty));
+        tys.sort_by_key(|ty| (ty.full_name(), *ty));
+        tys.dedup();
+        tys
+    }
+
+    /// The type with its layout, if it was registered with one.
+    pub fn lty(&self, ty: Ty) -> Option<LTy> {
+        let layout = (*self.read().layouts.get(&ty)?)?;
+        Some(LTy { ty, layout })
+    }
+
+    /// Every type, in order of their full names.
+    pub fn tys(&self) -> Vec<Ty> {
+        self.read().by_name.values().flatten().copied().collect()
+    }
+
+    /// How many types there are.
+    pub fn len(&self) -> usize {
+        self.read().layouts.len()
+    }
+
+    /// Are there no types?
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn read(&self) -> RwLockReadGuard<'_, Entries> {
+        self.entries.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, Entries> {
+        self.entries.write().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn register_std(&self) {
+        use std::borrow::Cow;
+        use std::collections::{BTreeSet, HashSet, VecDeque};
+        use std::error::Error;
+        use std::rc::Rc;
+        use std::sync::Arc;
+        crate::register_types!(in self;
+            (), bool, char, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64,
+            &'static str, String, Box<str>, Rc<str>, Arc<str>, Cow<'static, str>,
+            Vec<u8>, Vec<u32>, Vec<u64>, Vec<i32>, Vec<i64>, Vec<f32>, Vec<f64>, Vec<String>, Vec<&'static str>,
+            Option<u8>, Option<u32>, Option<u64>, Option<usize>, Option<i32>, Option<i64>, Option<bool>,
+            Option<String>, Option<&'static str>,
+            HashMap<String, String>, HashSet<String>, BTreeMap<String, String>, BTreeSet<String>,
+            VecDeque<u8>, Box<[u8]>, Arc<[u8]>,
+            Box<dyn Any>, Box<dyn Any + Send>, Box<dyn Error>, Box<dyn Error + Send + Sync>,
+            std::io::Error, std::fmt::Error, std::num::ParseIntError, std::num::ParseFloatError,
+            std::string::FromUtf8Error, std::str::Utf8Error,
+            std::time::Duration, std::time::Instant, std::time::SystemTime,
+            std::path::PathBuf, std::ffi::OsString, std::ffi::CString,
+        );
+        for ty in [Ty::of::<str>(), Ty::of::<[u8]>(), Ty::of::<dyn Any>(), Ty::of::<dyn Any + Send>()] {
+            self.register_ty(ty);
+        }
+    }
+}
+
+/// The pretty name of `ty` under the builtin rules, which unlike [`Ty::name`] doesn't change
+/// with the rules in effect.
+fn builtin_pretty_name(ty: Ty) -> &'static str {
+    let Ok(mut name) = ty.parse() else { return ty.full_name() };
+    TyNameStyle::Pretty.apply_with_rules(PrettyRules::builtin_ref(), &mut name, |_| ());
+    intern(&TyNameStyle::Pretty.format(&name))
+}
+
+/// Registers types with the [global](TyRegistry::global) registry, or with another one.
+/// ```
+/// # use ezty::{register_types, Ty, TyRegistry};
+/// struct Row;
+/// register_types!(Row, Option<Row>);
+/// assert!(TyRegistry::global().by_name(std::any::type_name::<Row>()).is_some());
+///
+/// let registry = TyRegistry::new();
+/// register_types!(in &registry; Row, [Row; 2]);
+/// assert_eq!(registry.len(), 2);
+/// ```
+#[macro_export]
+macro_rules! register_types {
+    (in $registry:expr; $($ty:ty),* $(,)?) => {{
+        let registry: &$crate::TyRegistry = $registry;
+        $(registry.register::<$ty>();)*
+    }};
+    ($($ty:ty),* $(,)?) => {
+        $crate::register_types!(in $crate::TyRegistry::global(); $($ty),*)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TyRegistry;
+    use crate::{LTy, PrettyRules, Ty};
+    use std::alloc::Layout;
+    use std::any::{Any, TypeId};
+
+    #[test]
+    fn lookups() {
+        struct A;
+        struct B<T>(T);
+        let registry = TyRegistry::new();
+        assert!(registry.is_empty());
+        register_types!(in &registry; A, B<A>, B<u8>);
+        assert!(!registry.register::<A>());
+        assert!(registry.register_ty(Ty::of::<str>()));
+        assert_eq!(registry.len(), 4);
+
+        let a = Ty::of::<A>();
+        assert_eq!(registry.by_type_id(TypeId::of::<A>()), Some(a));
+        assert_eq!(registry.by_type_id(TypeId::of::<u8>()), None);
+        assert_eq!(registry.of_any(&B(0u8)), Some(Ty::of::<B<u8>>()));
+        assert_eq!(registry.by_name(a.full_name()), Some(a));
+        assert_eq!(registry.by_name("A"), None);
+        assert_eq!(registry.by_canonical_name(a.full_name()), Some(a));
+        assert_eq!(registry.by_pretty_name("str"), [Ty::of::<str>()]);
+        assert_eq!(registry.by_pretty_name(Ty::of::<B<A>>().name()), [Ty::of::<B<A>>()]);
+
+        let b = a.full_name().replace("::A", "::B<");
+        assert_eq!(registry.by_prefix(&b), [Ty::of::<B<A>>(), Ty::of::<B<u8>>()]);
+        assert_eq!(registry.by_prefix("st"), [Ty::of::<str>()]);
+        assert_eq!(registry.by_prefix("nope"), []);
+        assert_eq!(registry.tys().len(), 4);
+
+        assert_eq!(registry.lty(a), Some(LTy::of::<A>()));
+        assert_eq!(registry.lty(Ty::of::<str>()), None);
+        assert!(!registry.register_lty(LTy { ty: Ty::of::<str>(), layout: Layout::new::<u8>() }));
+        assert_eq!(registry.lty(Ty::of::<str>()).map(|l| l.layout()), Some(Layout::new::<u8>()));
+    }
+
+    #[test]
+    fn std() {
+        let registry = TyRegistry::global();
+        let payload: Box<dyn Any + Send> = Box::new("oops");
+        assert_eq!(registry.of_any(&*payload), Some(Ty::of::<&'static str>()));
+        let payload: Box<dyn Any + Send> = Box::new(String::new());
+        assert_eq!(registry.of_any(&*payload), Some(Ty::of::<String>()));
+        assert_eq!(registry.by_pretty_name("Box<dyn Any + Send>"), [Ty::of::<Box<dyn Any + Send>>()]);
+        assert_eq!(registry.by_pretty_name("u8"), [Ty::of::<u8>()]);
+        assert!(registry.by_prefix("Option<").contains(&Ty::of::<Option<String>>()));
+        assert!(registry.by_name("str").is_some());
+        assert_eq!(registry.by_canonical_name("std::vec::Vec<u8>"), Some(Ty::of::<Vec<u8>>()));
+        assert_eq!(registry.by_canonical_name("alloc::vec::Vec<u8>"), None);
+    }
+
+    #[test]
+    fn builtin_pretty_names() {
+        let registry = TyRegistry::new();
+        {
+            let _rules = PrettyRules::empty().alias::<u8>("Byte").override_scope();
+            register_types!(in &registry; Vec<u8>);
+            assert_eq!(Ty::of::<Vec<u8>>().name(), "alloc::vec::Vec<Byte>");
+            assert_eq!(registry.by_pretty_name("Vec<u8>"), [Ty::of::<Vec<u8>>()]);
+        }
+        assert_eq!(registry.by_pretty_name("Vec<u8>"), [Ty::of::<Vec<u8>>()]);
+        assert_eq!(registry.by_pretty_name("alloc::vec::Vec<Byte>"), []);
+    }
+}
@@ -0,0 +1,344 @@
+use std::cell::RefCell;
+use std::sync::{Arc, OnceLock};
+use crate::alias::Alias;
+use crate::parse::{GenericArgs, PathSegment, TypeName, TypePath};
+
+/// The rules used by [`pretty`](crate::pretty) to shorten paths.
+///
+/// The [`builtin`](Self::builtin) rules strip the module paths of common std types. Applications
+/// can add their own rules and [`install`](Self::install) them once at startup, and tests can
+/// [`override_scope`](Self::override_scope) them for the current thread.
+/// ```
+/// # use ezty::PrettyRules;
+/// let _rules = PrettyRules::builtin()
+///     .rewrite("my_app::db::pool::Pool", "db::Pool")
+///     .strip_matching("my_app::model::**")
+///     .override_scope();
+/// assert_eq!(ezty::pretty("my_app::db::pool::Pool<u8>"), "db::Pool<u8>");
+/// assert_eq!(ezty::pretty("my_app::model::user::User"), "User");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PrettyRules {
+    rules: Vec<Rule>,
+    aliases: Vec<Alias>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Rule {
+    /// `module::Base` becomes `Base`.
+    Strip { module: Vec<String>, base: String },
+    /// `from` becomes `to`.
+    Rewrite { from: Vec<String>, to: Vec<String> },
+    /// Paths matching `pattern` lose their module path.
+    StripMatching { pattern: Vec<String> },
+}
+
+impl PrettyRules {
+    /// No rules at all; paths are left as they are.
+    pub fn empty() -> Self {
+        PrettyRules::default()
+    }
+
+    /// The rules from `pretty.expr.rs`, which give every std type a short but unambiguous name,
+    /// eg `Vec`, `Arc`, `io::Error` & `hash_map::Iter`.
+    pub fn builtin() -> Self {
+        let pretty: &[(&str, &str)] = include!("pretty.expr.rs");
+        pretty.iter().fold(PrettyRules::empty(), |rules, &(from, to)| rules.rewrite(from, to))
+    }
+
+    /// Rewrites the paths of std, core & alloc types to their public `std::` re-exports, eg
+    /// `alloc::collections::btree::map::BTreeMap` to `std::collections::BTreeMap`.
+    ///
+    /// These are the rules of [`TyNameStyle::Canonical`](crate::TyNameStyle::Canonical), and
+    /// come from `canonical.expr.rs`.
+    pub fn canonical() -> Self {
+        let canonical: &[(&str, &str)] = include!("canonical.expr.rs");
+        canonical.iter().fold(PrettyRules::empty(), |rules, &(from, to)| rules.rewrite(from, to))
+    }
+
+    /// Strips `module` from the type `module::base`, eg `("alloc::vec::", "Vec")`.
+    ///
+    /// The trailing `::` of `module` is optional.
+    pub fn strip(mut self, module: &str, base: &str) -> Self {
+        self.rules.push(Rule::Strip { module: split(module), base: base.to_owned() });
+        self
+    }
+
+    /// Replaces the path `from` with `to`, eg `("my_app::db::pool::Pool", "db::Pool")`.
+    ///
+    /// Generic arguments are kept. This is the kind of rule that `pretty.expr.rs` contains.
+    pub fn rewrite(mut self, from: &str, to: &str) -> Self {
+        self.rules.push(Rule::Rewrite { from: split(from), to: split(to) });
+        self
+    }
+
+    /// Strips the module path from any type whose path matches `pattern`.
+    ///
+    /// In the pattern, `*` matches a single path segment and `**` matches any number of them, eg
+    /// `"my_app::**"` or `"*::error::Error"`.
+    pub fn strip_matching(mut self, pattern: &str) -> Self {
+        self.rules.push(Rule::StripMatching { pattern: split(pattern) });
+        self
+    }
+
+    /// Writes types that match the expansion of the type alias `E` as `name`.
+    ///
+    /// The alias's generic parameters are given as [`Param`](crate::Param)s. Aliases are applied
+    /// before any other rule, except in the [`Expanded`](crate::TyNameStyle::Expanded) style.
+    /// ```
+    /// # use ezty::{Param, PrettyRules, Ty};
+    /// # use std::sync::{Arc, RwLock};
+    /// # struct AppError;
+    /// type Res<T> = Result<T, AppError>;
+    /// type Shared<T> = Arc<RwLock<T>>;
+    /// let _rules = PrettyRules::builtin()
+    ///     .alias::<Res<Param<0>>>("Res")
+    ///     .alias::<Shared<Param<0>>>("Shared")
+    ///     .override_scope();
+    /// assert_eq!(Ty::of::<Res<Shared<Vec<u8>>>>().name(), "Res<Shared<Vec<u8>>>");
+    /// assert_eq!(Ty::of::<Result<u8, ()>>().name(), "Result<u8, ()>");
+    /// ```
+    ///
+    /// Panics if the [`type_name()`](std::any::type_name) of `E` can't be parsed.
+    #[track_caller]
+    pub fn alias<E: ?Sized>(self, name: &str) -> Self {
+        self.alias_expansion(std::any::type_name::<E>(), name)
+    }
+
+    #[track_caller]
+    fn alias_expansion(mut self, expansion: &str, name: &str) -> Self {
+        match Alias::new(expansion, name) {
+            Ok(alias) => self.aliases.push(alias),
+            Err(e) => panic!("can't alias `{expansion}` as `{name}`: {e}"),
+        }
+        self
+    }
+
+    /// Adds all of `other`'s rules after these ones.
+    pub fn extend(mut self, other: PrettyRules) -> Self {
+        self.rules.extend(other.rules);
+        self.aliases.extend(other.aliases);
+        self
+    }
+
+    /// Makes these the rules for every thread.
+    ///
+    /// This can only be done once, and must be done before any name is prettified; after that,
+    /// the rules have been fixed to the builtin ones.
+    pub fn install(self) -> Result<(), PrettyRules> {
+        GLOBAL.set(Arc::new(self)).map_err(Arc::unwrap_or_clone)
+    }
+
+    /// Uses these rules on the current thread until the guard is dropped.
+    ///
+    /// Dropping a guard also ends any overrides made after it.
+    pub fn override_scope(self) -> PrettyRulesGuard {
+        let depth = OVERRIDES.with(|o| {
+            let mut overrides = o.borrow_mut();
+            overrides.push(Arc::new(self));
+            overrides.len() - 1
+        });
+        PrettyRulesGuard { depth, _not_send: std::marker::PhantomData }
+    }
+
+    /// Calls `f` with the rules that are in effect on this thread.
+    pub fn with_current<R>(f: impl FnOnce(&PrettyRules) -> R) -> R {
+        let rules = OVERRIDES
+            .with(|o| o.borrow().last().cloned())
+            .unwrap_or_else(|| GLOBAL.get_or_init(|| Arc::new(PrettyRules::builtin())).clone());
+        f(&rules)
+    }
+
+    /// Are this thread's rules overridden?
+    pub(crate) fn is_overridden() -> bool {
+        OVERRIDES.with(|o| !o.borrow().is_empty())
+    }
+
+    /// The [`builtin`](Self::builtin) rules, built once.
+    pub(crate) fn builtin_ref() -> &'static PrettyRules {
+        static BUILTIN: OnceLock<PrettyRules> = OnceLock::new();
+        BUILTIN.get_or_init(PrettyRules::builtin)
+    }
+
+    /// The [`canonical`](Self::canonical) rules, built once.
+    pub(crate) fn canonical_ref() -> &'static PrettyRules {
+        static CANONICAL: OnceLock<PrettyRules> = OnceLock::new();
+        CANONICAL.get_or_init(PrettyRules::canonical)
+    }
+
+    /// Replaces `ty` with the first alias that matches it. Returns `true` if there was one.
+    pub(crate) fn apply_aliases(&self, ty: &mut TypeName) -> bool {
+        self.aliases.iter().any(|alias| alias.apply(ty))
+    }
+
+    /// Applies the first matching rule to `path`. Returns `true` if anything changed.
+    pub(crate) fn apply(&self, path: &mut TypePath) -> bool {
+        let Some((last, module)) = path.segments.split_last() else { return false };
+        let plain = module.iter().all(|s| s.args == GenericArgs::None);
+        for rule in &self.rules {
+            match rule {
+                Rule::Strip { module: strip, base } => {
+                    if plain && last.name == *base && names_eq(module, strip) {
+                        path.segments.drain(..module.len());
+                        return true;
+                    }
+                }
+                Rule::Rewrite { from, to } => {
+                    let Some((from_last, from_module)) = from.split_last() else { continue };
+                    if plain && last.name == *from_last && names_eq(module, from_module) {
+                        let args = path.segments.pop().map(|s| s.args).unwrap_or_default();
+                        path.segments = to
+                            .iter()
+                            .map(|name| PathSegment { name: name.clone(), args: GenericArgs::None })
+                            .collect();
+                        if let Some(last) = path.segments.last_mut() {
+                            last.args = args;
+                        }
+                        return true;
+                    }
+                }
+                Rule::StripMatching { pattern } => {
+                    if plain && !module.is_empty() && glob(pattern, &path.segments) {
+                        path.segments.drain(..module.len());
+                        return true;
+                    }
+                }
+            }
+        }
+        false
+    }
+}
+
+/// Restores the previous [`PrettyRules`] when dropped. See [`PrettyRules::override_scope`].
+#[must_use = "the override ends when the guard is dropped"]
+pub struct PrettyRulesGuard {
+    /// How many overrides there were before this one.
+    depth: usize,
+    _not_send: std::marker::PhantomData<*const ()>,
+}
+impl Drop for PrettyRulesGuard {
+    fn drop(&mut self) {
+        OVERRIDES.with(|o| o.borrow_mut().truncate(self.depth));
+    }
+}
+
+static GLOBAL: OnceLock<Arc<PrettyRules>> = OnceLock::new();
+thread_local! {
+    static OVERRIDES: RefCell<Vec<Arc<PrettyRules>>> = const { RefCell::new(Vec::new()) };
+}
+
+fn split(path: &str) -> Vec<String> {
+    let path = path.trim().trim_end_matches("::");
+    if path.is_empty() {
+        return vec![];
+    }
+    path.split("::").map(|s| s.trim().to_owned()).collect()
+}
+
+fn names_eq(segments: &[PathSegment], names: &[String]) -> bool {
+    segments.len() == names.len() && segments.iter().zip(names).all(|(s, n)| s.name == *n)
+}
+
+/// Matches segment names against a pattern of names, `*`, and `**`.
+fn glob(pattern: &[String], segments: &[PathSegment]) -> bool {
+    match (pattern.split_first(), segments.split_first()) {
+        (None, None) => true,
+        (Some((p, rest)), _) if p == "**" => {
+            (0..=segments.len()).any(|skip| glob(rest, &segments[skip..]))
+        }
+        (Some((p, rest)), Some((s, segments))) => (p == "*" || *p == s.name) && glob(rest, segments),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PrettyRules;
+    use crate::pretty;
+
+    #[test]
+    fn builtin() {
+        assert_eq!(PrettyRules::builtin(), PrettyRules::builtin().extend(PrettyRules::empty()));
+        assert_eq!(pretty("alloc::vec::Vec<u8>"), "Vec<u8>");
+    }
+
+    #[test]
+    fn scoped() {
+        {
+            let _rules = PrettyRules::empty().override_scope();
+            assert_eq!(pretty("alloc::vec::Vec<u8>"), "alloc::vec::Vec<u8>");
+            {
+                let _rules = PrettyRules::empty().strip("alloc::vec", "Vec").override_scope();
+                assert_eq!(pretty("alloc::vec::Vec<u8>"), "Vec<u8>");
+            }
+            assert_eq!(pretty("alloc::vec::Vec<u8>"), "alloc::vec::Vec<u8>");
+        }
+        assert_eq!(pretty("alloc::vec::Vec<u8>"), "Vec<u8>");
+    }
+
+    #[test]
+    fn dropped_out_of_order() {
+        let outer = PrettyRules::empty().override_scope();
+        let inner = PrettyRules::empty().strip("alloc::vec", "Vec").override_scope();
+        drop(outer);
+        assert_eq!(pretty("alloc::vec::Vec<u8>"), "Vec<u8>");
+        assert!(!PrettyRules::is_overridden());
+        drop(inner);
+        assert!(!PrettyRules::is_overridden());
+
+        struct Scoped {
+            _rules: super::PrettyRulesGuard,
+        }
+        let first = Scoped { _rules: PrettyRules::empty().override_scope() };
+        let second = Scoped { _rules: PrettyRules::empty().strip("alloc::vec", "Vec").override_scope() };
+        let third = PrettyRules::empty().override_scope();
+        drop(third);
+        assert_eq!(pretty("alloc::vec::Vec<u8>"), "Vec<u8>");
+        drop(first);
+        drop(second);
+        assert!(!PrettyRules::is_overridden());
+    }
+
+    #[test]
+    #[should_panic(expected = "can't alias")]
+    fn unparsable_alias() {
+        let _ = PrettyRules::builtin().alias_expansion("alloc::vec::Vec<u8", "Bytes");
+    }
+
+    #[test]
+    fn rewrite() {
+        let _rules = PrettyRules::builtin().rewrite("app::a::b::Thing", "b::Thing").override_scope();
+        assert_eq!(pretty("app::a::b::Thing<alloc::vec::Vec<u8>>"), "b::Thing<Vec<u8>>");
+        assert_eq!(pretty("app::a::b::Thing2"), "app::a::b::Thing2");
+        assert_eq!(pretty("app::a::b::Thing::Inner"), "app::a::b::Thing::Inner");
+    }
+
+    #[test]
+    fn strip_matching() {
+        let _rules = PrettyRules::empty()
+            .strip_matching("app::**")
+            .strip_matching("*::error::Error")
+            .override_scope();
+        assert_eq!(pretty("app::Thing"), "Thing");
+        assert_eq!(pretty("app::a::b::Thing"), "Thing");
+        assert_eq!(pretty("other::Thing"), "other::Thing");
+        assert_eq!(pretty("lib::error::Error"), "Error");
+        assert_eq!(pretty("lib::inner::error::Error"), "lib::inner::error::Error");
+        assert_eq!(pretty("(app::A, app::a::B<other::C>)"), "(A, B<other::C>)");
+    }
+
+    #[test]
+    fn aliases() {
+        use crate::{Param, Ty, TyNameStyle};
+        use std::collections::HashMap;
+        type Map<V> = HashMap<String, V>;
+        let _rules = PrettyRules::builtin().alias::<Map<Param<0>>>("Map").override_scope();
+        let ty = Ty::of::<Option<Map<Map<u8>>>>();
+        assert_eq!(ty.name(), "Option<Map<Map<u8>>>");
+        assert_eq!(ty.display(TyNameStyle::Short).to_string(), "Option<Map<Map<u8>>>");
+        assert_eq!(ty.display(TyNameStyle::Expanded).to_string(), "Option<HashMap<String, HashMap<String, u8>>>");
+        assert_eq!(pretty("std::collections::hash::map::HashMap<u8, u8>"), "HashMap<u8, u8>");
+        let _rules = PrettyRules::builtin().extend(PrettyRules::empty().alias::<Map<u8>>("Bytes")).override_scope();
+        assert_eq!(ty.name(), "Option<HashMap<String, Bytes>>");
+    }
+}
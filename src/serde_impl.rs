@@ -0,0 +1,226 @@
+use std::fmt;
+use std::marker::PhantomData;
+use serde::de::{self, DeserializeSeed, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+use crate::style::TyNameStyle;
+use crate::{LTy, Ty, TyRegistry};
+
+/// Writes the [canonical](crate::canonical) name, eg `"std::vec::Vec<u8>"`.
+impl Serialize for Ty {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(TyNameStyle::Canonical.render_static(self.full_name()))
+    }
+}
+
+/// Looks the name up in the [global](TyRegistry::global) registry. Use
+/// [`TyRegistry::seed`] for other registries.
+impl<'de> Deserialize<'de> for Ty {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        TyRegistry::global().seed::<Self>().deserialize(deserializer)
+    }
+}
+
+/// Writes a struct of the [canonical](crate::canonical) name, and the size & alignment of the
+/// type.
+impl Serialize for LTy {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut lty = serializer.serialize_struct("LTy", 3)?;
+        lty.serialize_field("ty", &self.ty)?;
+        lty.serialize_field("size", &self.layout.size())?;
+        lty.serialize_field("align", &self.layout.align())?;
+        lty.end()
+    }
+}
+
+/// Looks the name up in the [global](TyRegistry::global) registry, which must know the layout of
+/// the type. If the size or alignment are given, they must match it.
+impl<'de> Deserialize<'de> for LTy {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        TyRegistry::global().seed::<Self>().deserialize(deserializer)
+    }
+}
+
+/// Deserializes a [`Ty`] or [`LTy`] by looking up its name in a registry, with
+/// [`find`](TyRegistry::find). Made by [`TyRegistry::seed`].
+/// ```
+/// # use ezty::{Ty, TyRegistry};
+/// # use serde::de::DeserializeSeed;
+/// struct Save;
+/// let registry = TyRegistry::new();
+/// registry.register::<Save>();
+///
+/// let json = serde_json::to_string(&Ty::of::<Save>()).unwrap();
+/// let mut deserializer = serde_json::Deserializer::from_str(&json);
+/// let ty = registry.seed::<Ty>().deserialize(&mut deserializer).unwrap();
+/// assert_eq!(ty, Ty::of::<Save>());
+/// ```
+pub struct TySeed<'r, T> {
+    registry: &'r TyRegistry,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl TyRegistry {
+    /// Deserializes a [`Ty`] or [`LTy`] that is in this registry.
+    pub fn seed<T>(&self) -> TySeed<'_, T> {
+        TySeed { registry: self, marker: PhantomData }
+    }
+}
+
+impl<T> Clone for TySeed<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for TySeed<'_, T> {}
+
+impl<'de> DeserializeSeed<'de> for TySeed<'_, Ty> {
+    type Value = Ty;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Ty, D::Error> {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<'de> Visitor<'de> for TySeed<'_, Ty> {
+    type Value = Ty;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a type name")
+    }
+
+    fn visit_str<E: de::Error>(self, name: &str) -> Result<Ty, E> {
+        self.registry.find(name).map_err(E::custom)
+    }
+}
+
+const FIELDS: &[&str] = &["ty", "size", "align"];
+
+impl<'de> DeserializeSeed<'de> for TySeed<'_, LTy> {
+    type Value = LTy;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<LTy, D::Error> {
+        deserializer.deserialize_struct("LTy", FIELDS, self)
+    }
+}
+
+impl<'de> Visitor<'de> for TySeed<'_, LTy> {
+    type Value = LTy;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a type name, with its size & alignment")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<LTy, A::Error> {
+        let ty = seq.next_element_seed(self.registry.seed::<Ty>())?;
+        let ty = ty.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let size = seq.next_element()?;
+        let align = seq.next_element()?;
+        self.lty(ty, size, align)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<LTy, A::Error> {
+        let (mut ty, mut size, mut align) = (None, None, None);
+        while let Some(key) = map.next_key::<String>()? {
+            match &key[..] {
+                "ty" => ty = Some(map.next_value_seed(self.registry.seed::<Ty>())?),
+                "size" => size = Some(map.next_value()?),
+                "align" => align = Some(map.next_value()?),
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        let ty = ty.ok_or_else(|| de::Error::missing_field("ty"))?;
+        self.lty(ty, size, align)
+    }
+}
+
+impl TySeed<'_, LTy> {
+    fn lty<E: de::Error>(self, ty: Ty, size: Option<usize>, align: Option<usize>) -> Result<LTy, E> {
+        let Some(lty) = self.registry.lty(ty) else {
+            return Err(E::custom(format_args!("the layout of `{}` is unknown", ty.name())));
+        };
+        let layout = lty.layout();
+        if size.is_some_and(|size| size != layout.size()) || align.is_some_and(|align| align != layout.align()) {
+            let show = |n: Option<usize>| n.map_or("?".to_owned(), |n| n.to_string());
+            return Err(E::custom(format_args!(
+                "`{}` has size {} & align {}, but was serialized with size {} & align {}",
+                ty.name(),
+                layout.size(),
+                layout.align(),
+                show(size),
+                show(align),
+            )));
+        }
+        Ok(lty)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{LTy, Ty, TyRegistry};
+    use serde::de::DeserializeSeed;
+    use std::collections::HashMap;
+
+    fn from_json<T>(registry: &TyRegistry, json: &str) -> Result<T, String>
+    where
+        for<'r, 'de> crate::TySeed<'r, T>: DeserializeSeed<'de, Value = T>,
+    {
+        let mut deserializer = serde_json::Deserializer::from_str(json);
+        registry.seed::<T>().deserialize(&mut deserializer).map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn tys() {
+        assert_eq!(serde_json::to_string(&Ty::of::<Vec<u8>>()).unwrap(), r#""std::vec::Vec<u8>""#);
+        assert_eq!(
+            serde_json::to_string(&Ty::of::<HashMap<String, Option<u8>>>()).unwrap(),
+            r#""std::collections::HashMap<std::string::String, std::option::Option<u8>>""#,
+        );
+        let ty: Ty = serde_json::from_str(r#""std::vec::Vec<u8>""#).unwrap();
+        assert_eq!(ty, Ty::of::<Vec<u8>>());
+        let ty: Ty = serde_json::from_str(r#""alloc::vec::Vec<u8>""#).unwrap();
+        assert_eq!(ty, Ty::of::<Vec<u8>>());
+
+        struct Local;
+        let registry = TyRegistry::new();
+        registry.register::<Local>();
+        let json = serde_json::to_string(&Ty::of::<Local>()).unwrap();
+        assert_eq!(from_json(&registry, &json), Ok(Ty::of::<Local>()));
+        assert!(serde_json::from_str::<Ty>(&json).unwrap_err().to_string().starts_with("unknown type"));
+        assert_eq!(
+            from_json::<Ty>(&registry, r#""Vec<u9>""#),
+            Err("unknown type `Vec<u9>` at line 1 column 9".into()),
+        );
+        assert_eq!(
+            serde_json::from_str::<Ty>(r#""Vec<u9>""#).unwrap_err().to_string(),
+            "unknown type `Vec<u9>`; did you mean `Vec<u8>`? at line 1 column 9",
+        );
+    }
+
+    #[test]
+    fn ltys() {
+        let lty = LTy::of::<u32>();
+        let json = serde_json::to_string(&lty).unwrap();
+        assert_eq!(json, r#"{"ty":"u32","size":4,"align":4}"#);
+        assert_eq!(serde_json::from_str::<LTy>(&json).unwrap(), lty);
+        assert_eq!(serde_json::from_str::<LTy>(r#"{"ty":"u32"}"#).unwrap(), lty);
+        assert_eq!(serde_json::from_str::<LTy>(r#"["u32", 4]"#).unwrap(), lty);
+        assert_eq!(serde_json::from_str::<LTy>(r#"{"ty":"u32","size":4,"more":[]}"#).unwrap(), lty);
+
+        let error = |json| serde_json::from_str::<LTy>(json).unwrap_err().to_string();
+        assert_eq!(
+            error(r#"{"ty":"u32","size":8,"align":4}"#),
+            "`u32` has size 4 & align 4, but was serialized with size 8 & align 4 at line 1 column 31",
+        );
+        assert_eq!(
+            error(r#"["u32", 4, 2]"#),
+            "`u32` has size 4 & align 4, but was serialized with size 4 & align 2 at line 1 column 13",
+        );
+        assert_eq!(error(r#"{"ty":"str"}"#), "the layout of `str` is unknown at line 1 column 12");
+        assert_eq!(error(r#"{"size":4}"#), "missing field `ty` at line 1 column 10");
+        assert!(error(r#"{"ty":"u33"}"#).starts_with("unknown type `u33`; did you mean `u32`"));
+    }
+}
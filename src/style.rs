@@ -0,0 +1,467 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::str::FromStr;
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+use crate::intern::intern;
+use crate::parse::{is_closure_marker, Bound, TypeName, TypePath};
+use crate::rules::PrettyRules;
+use crate::{abbreviate, Abbreviation, Ty};
+
+/// How the name of a [`Ty`] is rendered. See [`Ty::display`].
+///
+/// The `Debug` impls of [`Ty`] & [`LTy`](crate::LTy) use the [default](Self::default_style)
+/// style, except for `{:#?}`, which is always [`Full`](Self::Full).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub enum TyNameStyle {
+    /// Exactly what [`type_name()`](std::any::type_name) says, eg
+    /// `alloc::vec::Vec<my_crate::db::Row>`.
+    Full,
+    /// The [`pretty`](crate::pretty) form, eg `Vec<my_crate::db::Row>`.
+    #[default]
+    Pretty,
+    /// Like [`Pretty`](Self::Pretty), but [type aliases](PrettyRules::alias) are left expanded.
+    Expanded,
+    /// Only the last segment of every path, eg `Vec<Row>`.
+    Short,
+    /// Std paths are replaced with their public re-exports, and other paths are left alone, eg
+    /// `std::vec::Vec<my_crate::db::Row>`. The names can be pasted into code.
+    Canonical,
+    /// Like [`Pretty`](Self::Pretty), but paths in the given crate are written relative to its
+    /// root, eg `Vec<db::Row>`.
+    CrateRelative(&'static str),
+}
+
+/// The name of the environment variable that [`TyNameStyle::default_style`] reads.
+///
+/// It holds one of `full`, `pretty`, `expanded`, `short`, `canonical`, or `crate=my_crate`.
+pub const TY_NAME_STYLE_VAR: &str = "EZTY_TY_NAME_STYLE";
+
+static DEFAULT: OnceLock<TyNameStyle> = OnceLock::new();
+
+impl TyNameStyle {
+    /// The style used by the `Debug` impls of [`Ty`] & [`LTy`](crate::LTy).
+    ///
+    /// Unless [`set_default`](Self::set_default) was called first, this is read from the
+    /// [`EZTY_TY_NAME_STYLE`](TY_NAME_STYLE_VAR) environment variable the first time it is needed,
+    /// falling back to [`Pretty`](Self::Pretty).
+    pub fn default_style() -> TyNameStyle {
+        *DEFAULT.get_or_init(|| {
+            std::env::var(TY_NAME_STYLE_VAR)
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or_default()
+        })
+    }
+
+    /// Chooses the [default style](Self::default_style) for the whole process. This can only be
+    /// done once, and only before the default is first used.
+    pub fn set_default(self) -> Result<(), TyNameStyle> {
+        DEFAULT.set(self)
+    }
+
+    /// Renders the output of [`type_name()`](std::any::type_name) in this style.
+    ///
+    /// Except in the [`Full`](Self::Full) & [`Canonical`](Self::Canonical) styles, closures and
+    /// async blocks are described rather than named, eg `closure in main`. The bounds of trait
+    /// objects are put in a consistent order, and, in the `Pretty`, `Expanded` & `CrateRelative`
+    /// styles, traits that the [rules](PrettyRules) don't shorten lose their paths, eg
+    /// `dyn AnyDebug + Send`.
+    ///
+    /// The input is borrowed back if nothing changed, or if it isn't a valid [`TypeName`].
+    pub fn render(self, name: &str) -> Cow<'_, str> {
+        if self == TyNameStyle::Full {
+            return Cow::Borrowed(name);
+        }
+        let Ok(mut parsed) = TypeName::parse(name) else {
+            return Cow::Borrowed(name);
+        };
+        self.apply(&mut parsed);
+        let rendered = self.format(&parsed);
+        if rendered == name {
+            Cow::Borrowed(name)
+        } else {
+            Cow::Owned(rendered)
+        }
+    }
+
+    /// Renders every type name found in some text in this style. See [`prettify_text`](crate::prettify_text).
+    pub fn render_text(self, text: &str) -> String {
+        crate::text::render_text(self, text)
+    }
+
+    /// Writes a name that has been [applied](Self::apply) in this style.
+    pub(crate) fn format(self, name: &TypeName) -> String {
+        match self {
+            TyNameStyle::Full | TyNameStyle::Canonical => name.to_string(),
+            _ => format!("{name:#}"),
+        }
+    }
+
+    /// Rewrites a parsed name in this style.
+    pub(crate) fn apply(self, name: &mut TypeName) {
+        self.apply_with(name, |_| ());
+    }
+
+    /// Like [`apply`](Self::apply), but for the names of functions & other items as well as
+    /// types: the longest prefix of a path that a rule matches is rewritten, so the
+    /// `alloc::vec::Vec<u8>` of `alloc::vec::Vec<u8>::push` is shortened too.
+    pub(crate) fn apply_to_item(self, name: &mut TypeName) {
+        PrettyRules::with_current(|rules| {
+            let rules = match self {
+                TyNameStyle::Pretty | TyNameStyle::Expanded | TyNameStyle::CrateRelative(_) => rules,
+                TyNameStyle::Canonical => PrettyRules::canonical_ref(),
+                TyNameStyle::Full | TyNameStyle::Short => return,
+            };
+            name.for_each_path_mut(&mut |path| {
+                for len in (1..path.segments.len()).rev() {
+                    let mut prefix = TypePath { segments: path.segments[..len].to_vec() };
+                    if rules.apply(&mut prefix) {
+                        path.segments.splice(..len, prefix.segments);
+                        return;
+                    }
+                }
+            });
+        });
+        self.apply(name);
+    }
+
+    /// Like [`apply`](Self::apply), but calls `before_paths` once aliases have been applied,
+    /// just before the paths are shortened. From then on, [`TypeName::for_each_path_mut`] visits
+    /// the same paths in the same order.
+    pub(crate) fn apply_with(self, name: &mut TypeName, before_paths: impl FnOnce(&mut TypeName)) {
+        if self == TyNameStyle::Full {
+            return before_paths(name);
+        }
+        PrettyRules::with_current(|rules| self.apply_with_rules(rules, name, before_paths));
+    }
+
+    /// Like [`apply_with`](Self::apply_with), but with `rules` rather than the ones in effect.
+    pub(crate) fn apply_with_rules(
+        self,
+        rules: &PrettyRules,
+        name: &mut TypeName,
+        before_paths: impl FnOnce(&mut TypeName),
+    ) {
+        if self == TyNameStyle::Full {
+            return before_paths(name);
+        }
+        name.for_each_type_mut(&mut |ty| {
+            if matches!(self, TyNameStyle::Pretty | TyNameStyle::Short | TyNameStyle::CrateRelative(_)) {
+                rules.apply_aliases(ty);
+            }
+            if let TypeName::Dyn(bounds) | TypeName::Impl(bounds) = ty {
+                let strip = matches!(self, TyNameStyle::Pretty | TyNameStyle::Expanded | TyNameStyle::CrateRelative(_));
+                tidy_bounds(bounds, strip.then_some(rules));
+            }
+        });
+        before_paths(name);
+        name.for_each_path_mut(&mut |path| match self {
+            TyNameStyle::Full => (),
+            TyNameStyle::Pretty | TyNameStyle::Expanded => {
+                rules.apply(path);
+            }
+            TyNameStyle::Canonical => {
+                PrettyRules::canonical_ref().apply(path);
+            }
+            TyNameStyle::Short => keep_last(path, 1),
+            TyNameStyle::CrateRelative(krate) => {
+                if path.segments.len() > 1 && path.segments[0].name == krate {
+                    path.segments.remove(0);
+                } else {
+                    rules.apply(path);
+                }
+            }
+        });
+    }
+}
+
+/// Keeps the last `n` segments of `path`, not counting the markers of closures, eg
+/// `main::{{closure}}` rather than `{{closure}}`.
+pub(crate) fn keep_last(path: &mut TypePath, n: usize) {
+    let mut kept = 0;
+    let start = path.segments.iter().rposition(|s| {
+        kept += !is_closure_marker(&s.name) as usize;
+        kept == n
+    });
+    path.segments.drain(..start.unwrap_or(0));
+}
+
+/// The auto traits, in the order they are written after the main trait of a trait object.
+const AUTO_TRAITS: &[&str] = &["Send", "Sync", "Unpin", "UnwindSafe", "RefUnwindSafe"];
+
+/// Puts the main trait of a trait object first, then its auto traits, then its lifetime, eg
+/// `dyn Error + Send + Sync + 'static`.
+///
+/// If `rules` are given, the traits are shortened by them, and those that no rule shortens lose
+/// their whole path, eg `dyn my_app::db::Store` becomes `dyn Store`.
+fn tidy_bounds(bounds: &mut [Bound], rules: Option<&PrettyRules>) {
+    if let Some(rules) = rules {
+        for bound in bounds.iter_mut() {
+            if let Bound::Trait { path, .. } = bound {
+                if !rules.apply(path) {
+                    let n = path.segments.len().saturating_sub(1);
+                    path.segments.drain(..n);
+                }
+            }
+        }
+    }
+    sort_bounds(bounds);
+}
+
+/// Puts the bounds of a trait object in the order of [`tidy_bounds`], without changing them.
+pub(crate) fn sort_bounds(bounds: &mut [Bound]) {
+    bounds.sort_by_key(|bound| match bound {
+        Bound::Trait { path, .. } => {
+            let auto = path.base_name().and_then(|name| AUTO_TRAITS.iter().position(|&a| a == name));
+            auto.map_or((0, 0), |i| (1, i))
+        }
+        Bound::Lifetime(_) => (2, 0),
+    });
+}
+
+impl TyNameStyle {
+    /// Like [`render`](Self::render), but the result is cached, so a type's name is only
+    /// rendered once per style.
+    ///
+    /// Names rendered while a thread has [overridden](PrettyRules::override_scope) the rules
+    /// aren't cached, but they are still interned.
+    pub fn render_static(self, name: &'static str) -> &'static str {
+        type Cache = RwLock<HashMap<(TyNameStyle, usize, usize), &'static str>>;
+        static CACHE: OnceLock<Cache> = OnceLock::new();
+        if self == TyNameStyle::Full {
+            return name;
+        }
+        let render = |name| match self.render(name) {
+            Cow::Borrowed(name) => name,
+            Cow::Owned(name) => intern(&name),
+        };
+        if PrettyRules::is_overridden() {
+            return render(name);
+        }
+        // The name's address is a cheaper key than its (often long) contents.
+        let key = (self, name.as_ptr() as usize, name.len());
+        let cache = CACHE.get_or_init(Default::default);
+        if let Some(&rendered) = cache.read().unwrap_or_else(|e| e.into_inner()).get(&key) {
+            return rendered;
+        }
+        let rendered = render(name);
+        cache.write().unwrap_or_else(|e| e.into_inner()).insert(key, rendered);
+        rendered
+    }
+}
+
+/// The error returned when parsing an unknown [`TyNameStyle`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnknownStyle(pub String);
+impl fmt::Display for UnknownStyle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown type name style {:?}; expected full, pretty, expanded, short, canonical, or crate=NAME", self.0)
+    }
+}
+impl std::error::Error for UnknownStyle {}
+
+impl FromStr for TyNameStyle {
+    type Err = UnknownStyle;
+    /// Parses `full`, `pretty`, `expanded`, `short`, `canonical`, or `crate=my_crate`.
+    ///
+    /// The crate name of [`CrateRelative`](Self::CrateRelative) is leaked.
+    fn from_str(s: &str) -> Result<Self, UnknownStyle> {
+        let s = s.trim();
+        Ok(match &s.to_ascii_lowercase()[..] {
+            "full" => TyNameStyle::Full,
+            "pretty" => TyNameStyle::Pretty,
+            "expanded" => TyNameStyle::Expanded,
+            "short" => TyNameStyle::Short,
+            "canonical" => TyNameStyle::Canonical,
+            _ => match s.split_once('=') {
+                Some((key, krate)) if key.trim().eq_ignore_ascii_case("crate") && !krate.trim().is_empty() => {
+                    TyNameStyle::CrateRelative(Box::leak(krate.trim().into()))
+                }
+                _ => return Err(UnknownStyle(s.to_owned())),
+            },
+        })
+    }
+}
+
+/// Formats the name of a [`Ty`] in a particular [`TyNameStyle`]. Returned by [`Ty::display`].
+///
+/// The name can be [abbreviated](abbreviate); a precision, as in `{:.40}`, limits its width.
+/// A width, fill & alignment pad it, as in `{:>40}`.
+#[derive(Copy, Clone)]
+pub struct TyDisplay {
+    pub(crate) ty: Ty,
+    pub(crate) style: TyNameStyle,
+    pub(crate) abbreviation: Abbreviation,
+}
+impl TyDisplay {
+    /// Replaces generic arguments nested deeper than this with `…`.
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.abbreviation.max_depth = Some(max_depth);
+        self
+    }
+    /// Shortens the name to at most this many `char`s, if possible.
+    pub fn max_width(mut self, max_width: usize) -> Self {
+        self.abbreviation.max_width = Some(max_width);
+        self
+    }
+}
+impl fmt::Display for TyDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = self.style.render_static(self.ty.full_name());
+        let mut abbreviation = self.abbreviation;
+        if let Some(precision) = f.precision() {
+            abbreviation.max_width = Some(abbreviation.max_width.map_or(precision, |w| w.min(precision)));
+        }
+        if abbreviation.is_unlimited() {
+            pad(f, name)
+        } else {
+            pad(f, &abbreviate(name, abbreviation))
+        }
+    }
+}
+
+/// Like [`fmt::Formatter::pad`], but without cutting `s` to the precision, which has already been
+/// used to [abbreviate] it.
+fn pad(f: &mut fmt::Formatter, s: &str) -> fmt::Result {
+    let fill = f.width().unwrap_or(0).saturating_sub(s.chars().count());
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Right) => (fill, 0),
+        Some(fmt::Alignment::Center) => (fill / 2, fill - fill / 2),
+        Some(fmt::Alignment::Left) | None => (0, fill),
+    };
+    let padding = |n| std::iter::repeat_n(f.fill(), n).collect::<String>();
+    let (before, after) = (padding(before), padding(after));
+    f.write_str(&before)?;
+    f.write_str(s)?;
+    f.write_str(&after)
+}
+impl fmt::Debug for TyDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TyNameStyle;
+    use crate::{Ty, LTy};
+
+    mod db {
+        pub struct Row;
+    }
+
+    #[test]
+    fn styles() {
+        let ty = Ty::of::<Vec<Option<db::Row>>>();
+        assert_eq!(
+            ty.display(TyNameStyle::Full).to_string(),
+            "alloc::vec::Vec<core::option::Option<ezty::style::tests::db::Row>>",
+        );
+        assert_eq!(ty.display(TyNameStyle::Pretty).to_string(), "Vec<Option<ezty::style::tests::db::Row>>");
+        assert_eq!(ty.display(TyNameStyle::Short).to_string(), "Vec<Option<Row>>");
+        assert_eq!(
+            ty.display(TyNameStyle::Canonical).to_string(),
+            "std::vec::Vec<std::option::Option<ezty::style::tests::db::Row>>",
+        );
+        assert_eq!(
+            ty.display(TyNameStyle::CrateRelative("ezty")).to_string(),
+            "Vec<Option<style::tests::db::Row>>",
+        );
+        assert_eq!(format!("{:#?}", ty), "alloc::vec::Vec<core::option::Option<ezty::style::tests::db::Row>>");
+        assert_eq!(format!("{:#?}", LTy::of::<Vec<u8>>()), "alloc::vec::Vec<u8>");
+        assert_eq!(LTy::of::<Vec<db::Row>>().display(TyNameStyle::Short).to_string(), "Vec<Row>");
+    }
+
+    #[test]
+    fn short_closures() {
+        let f = || ();
+        fn ty_of<T: 'static>(_: &T) -> Ty { Ty::of::<T>() }
+        assert_eq!(ty_of(&f).display(TyNameStyle::Short).to_string(), "closure in short_closures");
+        let g = || || ();
+        assert_eq!(ty_of(&g()).display(TyNameStyle::Short).to_string(), "closure in closure in short_closures");
+        assert_eq!(
+            ty_of(&g()).display(TyNameStyle::Full).to_string(),
+            "ezty::style::tests::short_closures::{{closure}}::{{closure}}",
+        );
+    }
+
+    #[test]
+    fn trait_objects() {
+        let render = |style: TyNameStyle, name| style.render(name).into_owned();
+        let name = "alloc::boxed::Box<dyn core::marker::Sync + core::fmt::Debug + core::marker::Send>";
+        assert_eq!(render(TyNameStyle::Pretty, name), "Box<dyn Debug + Send + Sync>");
+        assert_eq!(render(TyNameStyle::Pretty, "&dyn core::fmt::Write"), "&dyn fmt::Write");
+        assert_eq!(render(TyNameStyle::Short, name), "Box<dyn Debug + Send + Sync>");
+        assert_eq!(
+            render(TyNameStyle::Canonical, name),
+            "std::boxed::Box<dyn std::fmt::Debug + std::marker::Send + std::marker::Sync>",
+        );
+        assert_eq!(render(TyNameStyle::Full, name), name);
+        assert_eq!(
+            render(TyNameStyle::Pretty, "&dyn core::marker::Send + core::panic::unwind_safe::RefUnwindSafe + core::panic::unwind_safe::UnwindSafe"),
+            "&dyn Send + UnwindSafe + RefUnwindSafe",
+        );
+        assert_eq!(
+            render(TyNameStyle::Pretty, "alloc::boxed::Box<dyn core::iter::traits::iterator::Iterator<Item = alloc::string::String> + core::marker::Send>"),
+            "Box<dyn Iterator<Item = String> + Send>",
+        );
+        assert_eq!(
+            render(TyNameStyle::CrateRelative("app"), "&(dyn 'static + app::db::Store<app::db::Row> + core::marker::Send)"),
+            "&dyn Store<db::Row> + Send + 'static",
+        );
+        assert_eq!(render(TyNameStyle::Pretty, "impl core::marker::Send + app::Job"), "impl Job + Send");
+    }
+
+    #[test]
+    fn cached() {
+        let ty = Ty::of::<Vec<db::Row>>();
+        let a = TyNameStyle::Short.render_static(ty.full_name());
+        let b = TyNameStyle::Short.render_static(ty.full_name());
+        assert_eq!(a, "Vec<Row>");
+        assert!(std::ptr::eq(a, b));
+        assert!(std::ptr::eq(ty.name(), ty.name()));
+        {
+            let _rules = crate::PrettyRules::empty().override_scope();
+            assert_eq!(ty.name(), "alloc::vec::Vec<ezty::style::tests::db::Row>");
+        }
+        assert_eq!(ty.name(), "Vec<ezty::style::tests::db::Row>");
+    }
+
+    #[test]
+    fn padded() {
+        let ty = Ty::of::<Vec<db::Row>>().display(TyNameStyle::Short);
+        assert_eq!(format!("{ty:>12}"), "    Vec<Row>");
+        assert_eq!(format!("{ty:-<12}|"), "Vec<Row>----|");
+        assert_eq!(format!("{ty:^12}"), "  Vec<Row>  ");
+        assert_eq!(format!("{ty:4}"), "Vec<Row>");
+        assert_eq!(format!("[{:>8}]", Ty::of::<u8>().display(TyNameStyle::Pretty)), "[      u8]");
+    }
+
+    #[test]
+    fn abbreviated() {
+        let ty = Ty::of::<Option<Vec<Option<db::Row>>>>();
+        assert_eq!(format!("{:.20?}", ty), "Option<Vec<…>>");
+        assert_eq!(format!("{:.20?}", LTy::of::<Option<Vec<Option<db::Row>>>>()), "Option<Vec<…>>");
+        assert_eq!(format!("{:.20}", ty.display(TyNameStyle::Short)), "Option<Vec<…>>");
+        assert_eq!(format!("{:.30}", ty.display(TyNameStyle::Short)), "Option<Vec<Option<Row>>>");
+        assert_eq!(ty.display(TyNameStyle::Short).max_depth(1).to_string(), "Option<Vec<…>>");
+        assert_eq!(format!("{:.10}", ty.display(TyNameStyle::Short).max_width(20)), "Option<…>");
+        assert_eq!(format!("{:>12.10}", ty.display(TyNameStyle::Short)), "   Option<…>");
+        assert_eq!(format!("{:.3}|", ty.display(TyNameStyle::Short)), "Option<…>|");
+        let ty = Ty::of::<Option<Vec<fn()>>>();
+        assert_eq!(format!("{:.8}", ty.display(TyNameStyle::Short).max_depth(2)), "Option<…>");
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!("full".parse(), Ok(TyNameStyle::Full));
+        assert_eq!(" Pretty ".parse(), Ok(TyNameStyle::Pretty));
+        assert_eq!("expanded".parse(), Ok(TyNameStyle::Expanded));
+        assert_eq!("short".parse(), Ok(TyNameStyle::Short));
+        assert_eq!("canonical".parse(), Ok(TyNameStyle::Canonical));
+        assert_eq!("crate=my_app".parse(), Ok(TyNameStyle::CrateRelative("my_app")));
+        assert!("crate=".parse::<TyNameStyle>().is_err());
+        assert!("loud".parse::<TyNameStyle>().is_err());
+    }
+}
@@ -0,0 +1,273 @@
+use std::error::Error;
+use std::fmt;
+use crate::parse::{TypeName, TypePath};
+use crate::style::TyNameStyle;
+use crate::{unambiguous_names, Ty, TyRegistry};
+
+/// A type that might be the one meant by a name. Returned by [`suggest`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Suggestion {
+    pub ty: Ty,
+    /// How different the names are; `0` if they're the same.
+    pub distance: usize,
+}
+
+/// Finds the types in `tys` whose names are close to `name`, closest first.
+///
+/// Both names are [prettified](crate::pretty) and compared part by part, so a typo in a generic
+/// argument only counts against that argument. Mistakes in the names of types count three
+/// times as much as mistakes in their module paths, which can also be left out. Names that are
+/// too different aren't suggested at all.
+/// ```
+/// # use ezty::{suggest, Ty};
+/// # use std::collections::HashMap;
+/// let tys = [Ty::of::<Vec<i32>>(), Ty::of::<Vec<u8>>(), Ty::of::<HashMap<String, u8>>()];
+/// let suggestions = suggest("Vec<i23>", &tys);
+/// assert_eq!(suggestions[0].ty, Ty::of::<Vec<i32>>());
+/// assert_eq!(suggest("HashMap<Sting, u8>", &tys)[0].ty, Ty::of::<HashMap<String, u8>>());
+/// assert!(suggest("Option<bool>", &tys).is_empty());
+/// ```
+pub fn suggest(name: &str, tys: &[Ty]) -> Vec<Suggestion> {
+    let query = TypeName::parse(name).ok().map(|mut query| {
+        TyNameStyle::Pretty.apply(&mut query);
+        query
+    });
+    let mut suggestions: Vec<(Suggestion, &str)> = tys
+        .iter()
+        .filter_map(|&ty| {
+            let (distance, limit) = match (&query, ty.parse()) {
+                (Some(query), Ok(mut candidate)) => {
+                    TyNameStyle::Pretty.apply(&mut candidate);
+                    (distance(query, &candidate), size(query))
+                }
+                _ => (edit_distance(name, ty.name()), name.chars().count()),
+            };
+            (distance * 3 <= limit).then_some((Suggestion { ty, distance }, ty.name()))
+        })
+        .collect();
+    suggestions.sort_by_key(|&(s, name)| (s.distance, name, s.ty));
+    suggestions.dedup_by_key(|(s, _)| s.ty);
+    suggestions.into_iter().map(|(s, _)| s).collect()
+}
+
+/// The error for a name that isn't any of the known types. Returned by
+/// [`TyRegistry::find`](crate::TyRegistry::find).
+///
+/// It [displays](fmt::Display) the closest [suggestions](suggest), eg "unknown type `Vec<i23>`;
+/// did you mean `Vec<i32>`?".
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnknownTy {
+    pub name: String,
+    /// The closest types, closest first.
+    pub suggestions: Vec<Ty>,
+}
+
+/// How many suggestions an [`UnknownTy`] lists.
+const LISTED: usize = 3;
+
+impl UnknownTy {
+    pub fn new(name: &str, tys: &[Ty]) -> Self {
+        let suggestions = suggest(name, tys).into_iter().map(|s| s.ty).collect();
+        UnknownTy { name: name.to_owned(), suggestions }
+    }
+}
+
+impl fmt::Display for UnknownTy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown type `{}`", self.name)?;
+        let listed = &self.suggestions[..self.suggestions.len().min(LISTED)];
+        let names = unambiguous_names(listed);
+        for (i, name) in names.iter().enumerate() {
+            let sep = match i {
+                0 => "; did you mean ",
+                _ if i + 1 == names.len() => " or ",
+                _ => ", ",
+            };
+            write!(f, "{sep}`{name}`")?;
+        }
+        if !names.is_empty() {
+            f.write_str("?")?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for UnknownTy {}
+
+impl TyRegistry {
+    /// The type with this [`type_name()`](std::any::type_name), [`canonical`](crate::canonical)
+    /// or [`pretty`](crate::pretty) name. If there isn't one, or there are several with the
+    /// pretty name, the error [suggests](suggest) the closest types.
+    /// ```
+    /// # use ezty::{Ty, TyRegistry};
+    /// let registry = TyRegistry::with_std();
+    /// assert_eq!(registry.find("Vec<u8>"), Ok(Ty::of::<Vec<u8>>()));
+    /// let error = registry.find("Vec<u9>").unwrap_err();
+    /// assert_eq!(error.suggestions[0], Ty::of::<Vec<u8>>());
+    /// assert_eq!(error.to_string(), "unknown type `Vec<u9>`; did you mean `Vec<u8>`?");
+    /// ```
+    pub fn find(&self, name: &str) -> Result<Ty, UnknownTy> {
+        if let Some(ty) = self.by_name(name).or_else(|| self.by_canonical_name(name)) {
+            return Ok(ty);
+        }
+        match self.by_pretty_name(name)[..] {
+            [ty] => Ok(ty),
+            _ => Err(UnknownTy::new(name, &self.tys())),
+        }
+    }
+
+    /// The types whose names are close to `name`, closest first. See [`suggest`].
+    pub fn suggest(&self, name: &str) -> Vec<Suggestion> {
+        suggest(name, &self.tys())
+    }
+}
+
+const BASE_WEIGHT: usize = 3;
+
+/// The edit distance between two parsed names: the differences between each pair of matching
+/// types, plus the [sizes](size) of the types that only one name has.
+fn distance(a: &TypeName, b: &TypeName) -> usize {
+    let head = match (a, b) {
+        (TypeName::Path(a), TypeName::Path(b)) => path_distance(a, b),
+        _ => edit_distance(&skeleton(a), &skeleton(b)) * BASE_WEIGHT,
+    };
+    let (a, b) = (a.children(), b.children());
+    // The edit distance between the lists of children.
+    let mut row = vec![0];
+    for b in &b {
+        row.push(row[row.len() - 1] + size(b));
+    }
+    for a in &a {
+        let mut diagonal = row[0];
+        row[0] += size(a);
+        for (j, b) in b.iter().enumerate() {
+            let next = (row[j + 1] + size(a)).min(row[j] + size(b)).min(diagonal + distance(a, b));
+            diagonal = row[j + 1];
+            row[j + 1] = next;
+        }
+    }
+    head + row[b.len()]
+}
+
+/// Mistakes in base names count more. The module path only counts if `a` has one.
+fn path_distance(a: &TypePath, b: &TypePath) -> usize {
+    fn split(path: &TypePath) -> (&str, String) {
+        let names: Vec<&str> = path.segments.iter().map(|s| s.name.as_str()).collect();
+        let (base, module) = names.split_last().map_or(("", &[][..]), |(b, m)| (*b, m));
+        (base, module.join("::"))
+    }
+    let ((a_base, a_module), (b_base, b_module)) = (split(a), split(b));
+    let module = if a_module.is_empty() { 0 } else { edit_distance(&a_module, &b_module) };
+    edit_distance(a_base, b_base) * BASE_WEIGHT + module
+}
+
+/// How different `ty` is from nothing at all.
+fn size(ty: &TypeName) -> usize {
+    let head = match ty {
+        TypeName::Path(path) => path.base_name().map_or(0, |b| b.chars().count()) * BASE_WEIGHT,
+        _ => skeleton(ty).chars().count() * BASE_WEIGHT,
+    };
+    head + ty.children().into_iter().map(size).sum::<usize>()
+}
+
+/// The name without the types inside it, eg `[_; 3]`.
+fn skeleton(ty: &TypeName) -> String {
+    let mut ty = ty.clone();
+    for child in ty.children_mut() {
+        *child = TypeName::Infer;
+    }
+    format!("{ty:#}")
+}
+
+/// The optimal string alignment distance, which counts swapping two adjacent `char`s as one
+/// edit.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+    let mut rows = vec![(0..=b.len()).collect::<Vec<_>>(); 3];
+    for i in 1..=a.len() {
+        rows.rotate_left(1);
+        let [before, prev, row] = &mut rows[..] else { unreachable!() };
+        row[0] = i;
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            row[j] = (prev[j] + 1).min(row[j - 1] + 1).min(prev[j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                row[j] = row[j].min(before[j - 2] + 1);
+            }
+        }
+    }
+    rows[2][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{edit_distance, suggest, UnknownTy};
+    use crate::{register_types, Ty, TyRegistry};
+    use std::collections::HashMap;
+
+    #[test]
+    fn distances() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("abc", ""), 3);
+        assert_eq!(edit_distance("Sting", "String"), 1);
+        assert_eq!(edit_distance("i23", "i32"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("ca", "abc"), 3);
+    }
+
+    #[test]
+    fn ranked() {
+        let tys = [
+            Ty::of::<Vec<i32>>(),
+            Ty::of::<Vec<i128>>(),
+            Ty::of::<Vec<u8>>(),
+            Ty::of::<Option<i32>>(),
+            Ty::of::<HashMap<String, u8>>(),
+            Ty::of::<std::io::Error>(),
+            Ty::of::<std::fmt::Error>(),
+        ];
+        let ranked = |name| suggest(name, &tys).into_iter().map(|s| s.ty.name()).collect::<Vec<_>>();
+        assert_eq!(ranked("Vec<i23>"), ["Vec<i32>", "Vec<i128>"]);
+        assert_eq!(ranked("Vec<i32>"), ["Vec<i32>", "Vec<i128>"]);
+        assert_eq!(ranked("alloc::vec::Vec<i32>")[0], "Vec<i32>");
+        assert_eq!(ranked("Vex<i32>"), ["Vec<i32>"]);
+        assert_eq!(ranked("HashMap<Sting, u8>"), ["HashMap<String, u8>"]);
+        assert_eq!(ranked("HashMap<String>"), ["HashMap<String, u8>"]);
+        assert_eq!(ranked("io::Eror"), ["io::Error"]);
+        assert_eq!(ranked("fmt::Eror"), ["fmt::Error"]);
+        assert_eq!(ranked("Error"), ["fmt::Error", "io::Error"]);
+        assert_eq!(ranked("Strin"), Vec::<&str>::new());
+        assert_eq!(ranked("Vec<u8"), ["Vec<u8>"]);
+    }
+
+    #[test]
+    fn find() {
+        mod a {
+            pub struct Error;
+        }
+        mod b {
+            pub struct Error;
+        }
+        let registry = TyRegistry::new();
+        register_types!(in &registry; a::Error, b::Error, Vec<u8>);
+        assert_eq!(registry.find("Vec<u8>"), Ok(Ty::of::<Vec<u8>>()));
+        assert_eq!(registry.find("alloc::vec::Vec<u8>"), Ok(Ty::of::<Vec<u8>>()));
+        assert_eq!(registry.find("std::vec::Vec<u8>"), Ok(Ty::of::<Vec<u8>>()));
+        assert_eq!(registry.find(Ty::of::<a::Error>().full_name()), Ok(Ty::of::<a::Error>()));
+        let error = registry.find("Error").unwrap_err();
+        assert_eq!(error.suggestions.len(), 2);
+        assert_eq!(error.to_string(), "unknown type `Error`; did you mean `a::Error` or `b::Error`?");
+        assert_eq!(registry.suggest("Vec<u8>")[0].distance, 0);
+    }
+
+    #[test]
+    fn unknown() {
+        let tys = [Ty::of::<Vec<i32>>(), Ty::of::<Vec<i64>>(), Ty::of::<Vec<u32>>(), Ty::of::<Vec<u64>>()];
+        assert_eq!(
+            UnknownTy::new("Vec<i23>", &tys).to_string(),
+            "unknown type `Vec<i23>`; did you mean `Vec<i32>`, `Vec<i64>` or `Vec<u32>`?",
+        );
+        assert_eq!(UnknownTy::new("Vec<i64>", &tys[..1]).to_string(), "unknown type `Vec<i64>`; did you mean `Vec<i32>`?");
+        assert_eq!(UnknownTy::new("Box<str>", &tys).to_string(), "unknown type `Box<str>`");
+    }
+}
@@ -0,0 +1,256 @@
+use std::collections::HashMap;
+use std::fmt::{self, Write};
+use std::sync::OnceLock;
+use crate::kind::PRIMITIVES;
+use crate::parse::{is_closure_marker, GenericArgs, TypePath};
+use crate::rules::PrettyRules;
+use crate::style::TyNameStyle;
+use crate::Ty;
+
+/// Where std, core & alloc are documented.
+pub const STD_DOCS_URL: &str = "https://doc.rust-lang.org/std/";
+
+/// Formats the name of a [`Ty`] for a terminal, with ANSI escape codes. Returned by
+/// [`Ty::terminal`].
+///
+/// Module paths are dimmed, the names of types are bold, and brackets are coloured by how deeply
+/// they are nested. With [`links`](Self::links), types are also [OSC 8] hyperlinks to their
+/// documentation.
+/// ```
+/// # use ezty::Ty;
+/// assert_eq!(
+///     Ty::of::<Option<u8>>().terminal().to_string(),
+///     "\x1b[1mOption\x1b[22m\x1b[33m<\x1b[39m\x1b[1mu8\x1b[22m\x1b[33m>\x1b[39m",
+/// );
+/// ```
+///
+/// [OSC 8]: https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda
+#[derive(Clone, Debug)]
+pub struct TyTerminal {
+    pub(crate) ty: Ty,
+    pub(crate) style: TyNameStyle,
+    pub(crate) links: bool,
+    pub(crate) docs_url: Option<String>,
+}
+
+impl TyTerminal {
+    /// Renders the name in this style, rather than the [default](TyNameStyle::default_style).
+    pub fn style(mut self, style: TyNameStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Links std types to their pages under [`STD_DOCS_URL`], and the types of other crates to a
+    /// search of the [`docs_url`](Self::docs_url), if there is one.
+    pub fn links(mut self, links: bool) -> Self {
+        self.links = links;
+        self
+    }
+
+    /// Where other crates are documented, eg `"https://docs.example.com/"` for
+    /// `https://docs.example.com/my_crate/`. A `{crate}` in the URL is replaced by the name of
+    /// the crate, eg `"https://docs.rs/{crate}/latest/{crate}/"`.
+    ///
+    /// This also turns on [`links`](Self::links).
+    pub fn docs_url(mut self, url: impl Into<String>) -> Self {
+        self.docs_url = Some(url.into());
+        self.links = true;
+        self
+    }
+
+    /// The documentation of the type at `path`, which hasn't been shortened yet.
+    fn link(&self, path: &TypePath) -> Option<String> {
+        if !self.links || path.segments.iter().any(|s| is_closure_marker(&s.name)) {
+            return None;
+        }
+        let mut path = path.clone();
+        for segment in &mut path.segments {
+            segment.args = GenericArgs::None;
+        }
+        let Some(krate) = path.crate_name() else {
+            let name = path.base_name()?;
+            return PRIMITIVES.contains(&name).then(|| format!("{STD_DOCS_URL}primitive.{name}.html"));
+        };
+        let search = |docs: &str, path: &TypePath| format!("{docs}?search={path}&go_to_first=true");
+        if matches!(krate, "std" | "core" | "alloc") {
+            // Only the types of the builtin rules are known to have a page of their own.
+            let known = PrettyRules::builtin_ref().apply(&mut path.clone());
+            if !PrettyRules::canonical_ref().apply(&mut path) {
+                path.segments[0].name = "std".into();
+            }
+            let Some((name, [_, module @ ..])) = path.segments.split_last().filter(|_| known) else {
+                return Some(search(STD_DOCS_URL, &path));
+            };
+            let module: String = module.iter().map(|s| format!("{}/", s.name)).collect();
+            return Some(format!("{STD_DOCS_URL}{module}{}.{}.html", doc_kind(&path), name.name));
+        }
+        let docs = self.docs_url.as_ref()?;
+        let docs = if docs.contains("{crate}") {
+            docs.replace("{crate}", krate)
+        } else {
+            format!("{}/{krate}/", docs.trim_end_matches('/'))
+        };
+        Some(search(&docs, &path))
+    }
+}
+
+/// What sort of item the std type at the public `path` is, which names its page, eg `enum` for
+/// `std::option::Option`.
+fn doc_kind(path: &TypePath) -> &'static str {
+    static KINDS: OnceLock<HashMap<&str, &str>> = OnceLock::new();
+    let kinds = KINDS.get_or_init(|| {
+        let kinds: &[(&str, &str)] = include!("doc_kinds.expr.rs");
+        kinds.iter().copied().collect()
+    });
+    kinds.get(&*path.to_string()).copied().unwrap_or("struct")
+}
+
+// Paths are marked in the rendered name by these control characters, which are then replaced
+// by escape codes: `START index START module::PATH::Name END`.
+const START: char = '\u{1}';
+const NAME: char = '\u{2}';
+const END: char = '\u{3}';
+
+const BRACKET_COLOURS: &[u8] = &[33, 35, 36, 32];
+
+impl fmt::Display for TyTerminal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Ok(mut name) = self.ty.parse() else {
+            return f.write_str(&self.style.render(self.ty.full_name()));
+        };
+        let mut links = vec![];
+        self.style.apply_with(&mut name, |name| name.for_each_path_mut(&mut |path| links.push(self.link(path))));
+        let mut i = 0;
+        name.for_each_path_mut(&mut |path| {
+            if let Some(base) = path.segments.iter().rposition(|s| !is_closure_marker(&s.name)) {
+                let base = &mut path.segments[base].name;
+                *base = format!("{NAME}{base}{END}");
+                path.segments[0].name.insert_str(0, &format!("{START}{i}{START}"));
+            }
+            i += 1;
+        });
+        let marked = self.style.format(&name);
+
+        let mut chars = marked.chars().peekable();
+        let (mut depth, mut dim, mut dimmed, mut linked) = (0, false, false, false);
+        while let Some(c) = chars.next() {
+            match c {
+                START => {
+                    let index: String = chars.by_ref().take_while(|&c| c != START).collect();
+                    if let Some(Some(link)) = index.parse().ok().and_then(|i: usize| links.get(i)) {
+                        write!(f, "\x1b]8;;{link}\x1b\\")?;
+                        linked = true;
+                    }
+                    dim = true;
+                }
+                NAME => {
+                    if dimmed {
+                        f.write_str("\x1b[22m")?;
+                    }
+                    (dim, dimmed) = (false, false);
+                    f.write_str("\x1b[1m")?;
+                }
+                END => {
+                    f.write_str("\x1b[22m")?;
+                    if linked {
+                        f.write_str("\x1b]8;;\x1b\\")?;
+                        linked = false;
+                    }
+                }
+                '-' if chars.peek() == Some(&'>') => {
+                    chars.next();
+                    f.write_str("->")?;
+                }
+                '<' | '(' | '[' | '>' | ')' | ']' => {
+                    let open = matches!(c, '<' | '(' | '[');
+                    if open {
+                        depth += 1;
+                    }
+                    let colour = BRACKET_COLOURS[(depth.max(1) - 1) % BRACKET_COLOURS.len()];
+                    write!(f, "\x1b[{colour}m{c}\x1b[39m")?;
+                    if !open {
+                        depth = depth.saturating_sub(1);
+                    }
+                }
+                c => {
+                    if dim && !dimmed {
+                        f.write_str("\x1b[2m")?;
+                        dimmed = true;
+                    }
+                    f.write_char(c)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::TypeName;
+    use crate::{Ty, TyNameStyle};
+
+    mod db {
+        pub struct Row;
+    }
+
+    #[test]
+    fn highlighted() {
+        let ty = Ty::of::<Vec<(u8, db::Row)>>();
+        assert_eq!(
+            ty.terminal().to_string(),
+            "\x1b[1mVec\x1b[22m\x1b[33m<\x1b[39m\x1b[35m(\x1b[39m\x1b[1mu8\x1b[22m, \
+             \x1b[2mezty::terminal::tests::db::\x1b[22m\x1b[1mRow\x1b[22m\x1b[35m)\x1b[39m\x1b[33m>\x1b[39m",
+        );
+        assert_eq!(
+            ty.terminal().style(TyNameStyle::Short).to_string(),
+            "\x1b[1mVec\x1b[22m\x1b[33m<\x1b[39m\x1b[35m(\x1b[39m\x1b[1mu8\x1b[22m, \
+             \x1b[1mRow\x1b[22m\x1b[35m)\x1b[39m\x1b[33m>\x1b[39m",
+        );
+        assert_eq!(
+            Ty::of::<fn() -> u8>().terminal().to_string(),
+            "fn\x1b[33m(\x1b[39m\x1b[33m)\x1b[39m -> \x1b[1mu8\x1b[22m",
+        );
+        let f = || ();
+        fn ty_of<T: 'static>(_: &T) -> Ty { Ty::of::<T>() }
+        assert_eq!(ty_of(&f).terminal().links(true).to_string(), "closure in \x1b[1mhighlighted\x1b[22m");
+        assert_eq!(format!("{:?}", ty), "Vec<(u8, ezty::terminal::tests::db::Row)>");
+    }
+
+    #[test]
+    fn links() {
+        assert_eq!(
+            Ty::of::<Option<u8>>().terminal().links(true).to_string(),
+            "\x1b]8;;https://doc.rust-lang.org/std/option/enum.Option.html\x1b\\\
+             \x1b[1mOption\x1b[22m\x1b]8;;\x1b\\\x1b[33m<\x1b[39m\
+             \x1b]8;;https://doc.rust-lang.org/std/primitive.u8.html\x1b\\\x1b[1mu8\x1b[22m\x1b]8;;\x1b\\\
+             \x1b[33m>\x1b[39m",
+        );
+        let row = Ty::of::<db::Row>().terminal().style(TyNameStyle::Short);
+        assert_eq!(row.clone().links(true).to_string(), "\x1b[1mRow\x1b[22m");
+        assert_eq!(
+            row.clone().docs_url("https://docs.example.com").to_string(),
+            "\x1b]8;;https://docs.example.com/ezty/?search=ezty::terminal::tests::db::Row&go_to_first=true\x1b\\\
+             \x1b[1mRow\x1b[22m\x1b]8;;\x1b\\",
+        );
+        assert_eq!(
+            row.docs_url("https://docs.rs/{crate}/latest/{crate}/").to_string(),
+            "\x1b]8;;https://docs.rs/ezty/latest/ezty/?search=ezty::terminal::tests::db::Row&go_to_first=true\x1b\\\
+             \x1b[1mRow\x1b[22m\x1b]8;;\x1b\\",
+        );
+    }
+
+    #[test]
+    fn std_pages() {
+        let terminal = Ty::of::<u8>().terminal().links(true);
+        let link = |name: &str| terminal.link(TypeName::parse(name).unwrap().path().unwrap()).unwrap();
+        let std = |page: &str| format!("https://doc.rust-lang.org/std/{page}");
+        assert_eq!(link("alloc::vec::Vec"), std("vec/struct.Vec.html"));
+        assert_eq!(link("std::collections::hash::map::HashMap<u8, u8>"), std("collections/struct.HashMap.html"));
+        assert_eq!(link("core::fmt::Debug"), std("fmt/trait.Debug.html"));
+        assert_eq!(link("core::mem::maybe_uninit::MaybeUninit<u8>"), std("mem/union.MaybeUninit.html"));
+        assert_eq!(link("core::num::nonzero::NonZeroU32"), std("num/type.NonZeroU32.html"));
+        assert_eq!(link("std::sync::mutex::Mutex<u8>"), std("sync/struct.Mutex.html"));
+        assert_eq!(link("core::intrinsics::Unknown"), std("?search=std::intrinsics::Unknown&go_to_first=true"));
+    }
+}
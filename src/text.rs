@@ -0,0 +1,258 @@
+use std::collections::HashMap;
+use crate::demangle::demangle_raw;
+use crate::parse::TypeName;
+use crate::style::TyNameStyle;
+
+/// Finds the Rust type names in some text, such as a panic message, a backtrace or compiler
+/// output, and [prettifies](crate::pretty) them. Everything else is left alone.
+///
+/// Only paths with a `::` are rewritten, along with any generics, references or trait objects
+/// inside their brackets. Mangled symbols are [demangled](crate::demangle). To use another
+/// style, see [`TyNameStyle::render_text`].
+/// ```
+/// # use ezty::prettify_text;
+/// assert_eq!(
+///     prettify_text("expected `alloc::vec::Vec<alloc::string::String>`, found `u8`"),
+///     "expected `Vec<String>`, found `u8`",
+/// );
+/// assert_eq!(
+///     prettify_text("   2: <alloc::vec::Vec<u8> as core::fmt::Debug>::fmt"),
+///     "   2: <Vec<u8> as Debug>::fmt",
+/// );
+/// ```
+pub fn prettify_text(text: &str) -> String {
+    TyNameStyle::Pretty.render_text(text)
+}
+
+/// See [`TyNameStyle::render_text`].
+pub(crate) fn render_text(style: TyNameStyle, text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let ends = bracket_ends(text);
+    // Everything before `copied` is already in `out`.
+    let mut copied = 0;
+    let mut i = 0;
+    while let Some(c) = text[i..].chars().next() {
+        let at_boundary = text[..i].chars().next_back().is_none_or(|p| !is_ident(p) && p != ':' && p != '$');
+        if at_boundary {
+            if let Some((end, rendered)) = render_at(style, text, &ends, i) {
+                out.push_str(&text[copied..i]);
+                out.push_str(&rendered);
+                copied = end;
+                i = end;
+                continue;
+            }
+        }
+        i += c.len_utf8();
+    }
+    out.push_str(&text[copied..]);
+    out
+}
+
+/// Renders the name that starts at `start`, if there is one, and returns where it ends.
+fn render_at(style: TyNameStyle, text: &str, ends: &HashMap<usize, usize>, start: usize) -> Option<(usize, String)> {
+    if let Some(len) = mangled_len(&text[start..]) {
+        let end = start + len;
+        // This gives up on symbols that would demangle to something huge.
+        let demangled = demangle_raw(&text[start..end])?;
+        return Some((end, render_name(style, &demangled).unwrap_or(demangled)));
+    }
+    let end = path_end(text, ends, start)?;
+    let name = &text[start..end];
+    if !name.contains("::") {
+        return None;
+    }
+    Some((end, render_name(style, name)?))
+}
+
+fn render_name(style: TyNameStyle, name: &str) -> Option<String> {
+    let mut parsed = TypeName::parse(name).ok()?;
+    style.apply_to_item(&mut parsed);
+    Some(style.format(&parsed))
+}
+
+/// The length of the mangled symbol at the start of `text`, if there is one.
+fn mangled_len(text: &str) -> Option<usize> {
+    let symbol = text.strip_prefix('_').filter(|s| s.starts_with("_R") || s.starts_with("_ZN")).unwrap_or(text);
+    let scheme = if symbol.starts_with("_ZN") {
+        "_ZN"
+    } else if symbol.strip_prefix("_R").is_some_and(|s| s.starts_with(|c: char| c.is_ascii_uppercase())) {
+        "_R"
+    } else {
+        return None;
+    };
+    let len = text.find(|c: char| !(c.is_ascii_alphanumeric() || "_$.".contains(c))).unwrap_or(text.len());
+    (len > scheme.len() + 1).then_some(len)
+}
+
+/// Where the path that starts at `start` ends: a sequence of identifiers, `<…>` and `{…}`
+/// separated by `::`, optionally starting with a qualified `<T as Trait>`.
+fn path_end(text: &str, ends: &HashMap<usize, usize>, start: usize) -> Option<usize> {
+    let bracket_end = |open| ends.get(&open).copied();
+    let mut end = match text[start..].chars().next()? {
+        '<' => {
+            let close = bracket_end(start)?;
+            if !text[close..].starts_with("::") {
+                return None;
+            }
+            close
+        }
+        c if is_ident_start(c) => ident_end(text, start),
+        _ => return None,
+    };
+    loop {
+        if text[end..].starts_with('<') {
+            match bracket_end(end) {
+                Some(close) => end = close,
+                None => break,
+            }
+        }
+        let Some(next) = text[end..].strip_prefix("::") else { break };
+        let next_start = end + 2;
+        end = match next.chars().next() {
+            Some(c) if is_ident_start(c) => ident_end(text, next_start),
+            Some('{') => match bracket_end(next_start) {
+                Some(close) => close,
+                None => break,
+            },
+            // A turbofish; its brackets are taken on the next pass.
+            Some('<') => next_start,
+            _ => break,
+        };
+    }
+    Some(end)
+}
+
+fn ident_end(text: &str, start: usize) -> usize {
+    text[start..].find(|c: char| !is_ident(c)).map_or(text.len(), |len| start + len)
+}
+
+/// Where each bracket in `text` that closes on the same line does, by where it opens.
+///
+/// They are all found in one pass, as scanning from each `<` to the end of a line that is
+/// missing a `>` would take quadratic time.
+fn bracket_ends(text: &str) -> HashMap<usize, usize> {
+    let mut ends = HashMap::new();
+    let mut open = vec![];
+    let mut prev = ' ';
+    for (i, c) in text.char_indices() {
+        match c {
+            '<' | '(' | '[' | '{' => open.push(i),
+            // The arrow of a `fn() -> T`.
+            '>' if prev == '-' => {}
+            '>' | ')' | ']' | '}' => {
+                if let Some(start) = open.pop() {
+                    ends.insert(start, i + 1);
+                }
+            }
+            '\n' | '`' => open.clear(),
+            _ => {}
+        }
+        prev = c;
+    }
+    ends
+}
+
+fn is_ident_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+fn is_ident(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::prettify_text;
+    use crate::TyNameStyle;
+
+    #[test]
+    fn prose() {
+        assert_eq!(prettify_text(""), "");
+        assert_eq!(prettify_text("nothing to see"), "nothing to see");
+        assert_eq!(prettify_text("a < b, c > d"), "a < b, c > d");
+        assert_eq!(
+            prettify_text("called `core::option::Option::unwrap()` on a `None` value"),
+            "called `Option::unwrap()` on a `None` value",
+        );
+        assert_eq!(
+            prettify_text("type: alloc::collections::btree::map::BTreeMap<u8, (&str, fn(u8) -> u8)>."),
+            "type: BTreeMap<u8, (&str, fn(u8) -> u8)>.",
+        );
+        assert_eq!(
+            prettify_text("a Box<dyn core::error::Error + core::marker::Send> and a Vec<&core::cell::Cell<u8>>"),
+            "a Box<dyn error::Error + Send> and a Vec<&Cell<u8>>",
+        );
+        assert_eq!(prettify_text("x: alloc::vec::Vec<u8\ny"), "x: Vec<u8\ny");
+        assert_eq!(prettify_text("std::io::stdin, ünïcödé::Tÿpe"), "std::io::stdin, ünïcödé::Tÿpe");
+    }
+
+    #[test]
+    fn deeply_nested() {
+        let deep = |n| format!("{}u8{}", "alloc::vec::Vec<".repeat(n), ">".repeat(n));
+        assert_eq!(prettify_text(&deep(99)), format!("{}u8{}", "Vec<".repeat(99), ">".repeat(99)));
+        // Only the innermost names are shallow enough to parse.
+        let partly = format!("{}{}u8{}", "alloc::vec::Vec<".repeat(201), "Vec<".repeat(99), ">".repeat(300));
+        assert_eq!(prettify_text(&deep(300)), partly);
+        let symbol = format!("_RINvC1a1f{}hE", "R".repeat(100_000));
+        assert_eq!(prettify_text(&symbol), symbol);
+    }
+
+    #[test]
+    fn long_lines() {
+        // Each `<` used to be matched by scanning to the end of the line.
+        let line = format!("{}\n", "core::option::Option<".repeat(10_000));
+        assert_eq!(prettify_text(&line), line.replace("core::option::", ""));
+        let line = format!("x {}", "core::option::Option<u8> <".repeat(5_000));
+        assert_eq!(prettify_text(&line), line.replace("core::option::", ""));
+        // A symbol whose backrefs double its output at each level, as a line of a log.
+        let base62 = |mut n: usize| {
+            let mut digits = vec![];
+            loop {
+                digits.insert(0, b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ"[n % 62]);
+                n /= 62;
+                if n == 0 {
+                    break String::from_utf8(digits).unwrap();
+                }
+            }
+        };
+        let mut symbol = "_RINvC1a1fh".to_owned();
+        let mut prev = 8;
+        for _ in 0..30 {
+            let backref = format!("B{}_", base62(prev - 1));
+            prev = symbol.len() - 2;
+            symbol += &format!("T{backref}{backref}E");
+        }
+        symbol += "E";
+        assert_eq!(prettify_text(&symbol), symbol);
+    }
+
+    #[test]
+    fn backtrace() {
+        let trace = "\
+   0: core::result::unwrap_failed
+             at /rustc/f6e511eec7342f59a25f7c0534f1dbea00d01b14/library/core/src/result.rs:1652:5
+   1: core::result::Result<T,E>::unwrap
+   2: app::main::{{closure}}
+   3: <alloc::vec::Vec<u8> as core::fmt::Debug>::fmt
+   4: std::rt::lang_start::<()>
+   5: _ZN4core6option15Option$LT$T$GT$6expect17h0123456789abcdefE
+";
+        assert_eq!(prettify_text(trace), "\
+   0: core::result::unwrap_failed
+             at /rustc/f6e511eec7342f59a25f7c0534f1dbea00d01b14/library/core/src/result.rs:1652:5
+   1: Result<T, E>::unwrap
+   2: closure in main
+   3: <Vec<u8> as Debug>::fmt
+   4: std::rt::lang_start<()>
+   5: Option<T>::expect
+");
+    }
+
+    #[test]
+    fn styles() {
+        let text = "(alloc::vec::Vec<core::option::Option<u8>>)";
+        assert_eq!(TyNameStyle::Full.render_text(text), text);
+        assert_eq!(TyNameStyle::Short.render_text(text), "(Vec<Option<u8>>)");
+        assert_eq!(TyNameStyle::Canonical.render_text(text), "(std::vec::Vec<std::option::Option<u8>>)");
+    }
+}
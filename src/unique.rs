@@ -0,0 +1,214 @@
+use std::collections::HashMap;
+use crate::parse::TypeName;
+use crate::style::{keep_last, TyNameStyle};
+use crate::Ty;
+
+/// Names each of `tys` as briefly as possible, without giving distinct types the same name.
+///
+/// Every path starts out as just its last segment, as in [`TyNameStyle::Short`]. When distinct
+/// types' whole names collide, each name gets back just enough of its [`pretty`](crate::pretty)
+/// module paths to differ from the others, on whichever of its paths needs the fewest segments.
+/// Types that can't be told apart even by their full pretty names keep them; see
+/// [`name_collisions`].
+/// ```
+/// # use ezty::{unambiguous_names, Ty};
+/// mod config { pub struct Error; }
+/// mod net { pub struct Error; }
+/// let names = unambiguous_names(&[
+///     Ty::of::<config::Error>(),
+///     Ty::of::<net::Error>(),
+///     Ty::of::<std::io::Error>(),
+///     Ty::of::<String>(),
+/// ]);
+/// assert_eq!(names, ["config::Error", "net::Error", "io::Error", "String"]);
+/// ```
+pub fn unambiguous_names(tys: &[Ty]) -> Vec<String> {
+    let parsed: Vec<Option<TypeName>> = tys
+        .iter()
+        .map(|ty| {
+            let mut name = ty.parse().ok()?;
+            TyNameStyle::Pretty.apply(&mut name);
+            Some(name)
+        })
+        .collect();
+    // The segments of each path in each name, with their arguments left out.
+    let paths: Vec<Vec<Vec<String>>> = parsed
+        .iter()
+        .map(|name| {
+            let mut paths = vec![];
+            if let Some(name) = name {
+                name.clone().for_each_path_mut(&mut |path| {
+                    paths.push(path.segments.iter().map(|s| s.name.clone()).collect());
+                });
+            }
+            paths
+        })
+        .collect();
+    let render = |i: usize, segments: &[usize]| match &parsed[i] {
+        Some(name) => {
+            let mut name = name.clone();
+            let mut k = 0;
+            name.for_each_path_mut(&mut |path| {
+                keep_last(path, segments[k]);
+                k += 1;
+            });
+            format!("{name:#}")
+        }
+        None => tys[i].name().to_owned(),
+    };
+    // How many segments of each path in each name are kept.
+    let mut segments: Vec<Vec<usize>> = paths.iter().map(|paths| vec![1; paths.len()]).collect();
+    let mut names: Vec<String> = (0..tys.len()).map(|i| render(i, &segments[i])).collect();
+    loop {
+        let mut by_name: HashMap<&str, Vec<usize>> = HashMap::new();
+        for (i, name) in names.iter().enumerate() {
+            by_name.entry(name).or_default().push(i);
+        }
+        let mut longer = vec![];
+        for same in by_name.values() {
+            for &i in same {
+                let mut lengthened = segments[i].clone();
+                for &j in same.iter().filter(|&&j| tys[j] != tys[i]) {
+                    // The path that needs the fewest more segments to differ from the same path in
+                    // `j`'s name, and how many it needs in all.
+                    let needed = paths[i]
+                        .iter()
+                        .enumerate()
+                        .filter_map(|(k, path)| {
+                            let other = paths[j].get(k)?;
+                            let n = (segments[i][k] + 1..=path.len())
+                                .find(|&n| path[path.len() - n..] != other[other.len().saturating_sub(n)..])?;
+                            Some((n - segments[i][k], k, n))
+                        })
+                        .min();
+                    if let Some((_, k, n)) = needed {
+                        lengthened[k] = lengthened[k].max(n);
+                    }
+                }
+                let name = render(i, &lengthened);
+                if name != names[i] {
+                    longer.push((i, lengthened, name));
+                }
+            }
+        }
+        if longer.is_empty() {
+            return names;
+        }
+        for (i, lengthened, name) in longer {
+            segments[i] = lengthened;
+            names[i] = name;
+        }
+    }
+}
+
+/// Distinct types with the same [`name`](Ty::name). Returned by [`name_collisions`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NameCollision {
+    pub name: &'static str,
+    /// In the order they were given, without duplicates.
+    pub tys: Vec<Ty>,
+}
+
+/// Finds the distinct types in `tys` whose [`name`](Ty::name)s are identical.
+///
+/// The collisions are in the order that their names first appear in `tys`.
+/// ```
+/// # use ezty::{name_collisions, PrettyRules, Ty};
+/// mod a { pub struct Config; }
+/// mod b { pub struct Config; }
+/// let _rules = PrettyRules::builtin().strip_matching("**::Config").override_scope();
+/// let tys = [Ty::of::<a::Config>(), Ty::of::<u8>(), Ty::of::<b::Config>(), Ty::of::<a::Config>()];
+/// let collisions = name_collisions(&tys);
+/// assert_eq!(collisions.len(), 1);
+/// assert_eq!(collisions[0].name, "Config");
+/// assert_eq!(collisions[0].tys, [tys[0], tys[2]]);
+/// ```
+pub fn name_collisions(tys: &[Ty]) -> Vec<NameCollision> {
+    let mut collisions: Vec<NameCollision> = vec![];
+    let mut by_name: HashMap<&'static str, usize> = HashMap::new();
+    for &ty in tys {
+        let i = *by_name.entry(ty.name()).or_insert_with(|| {
+            collisions.push(NameCollision { name: ty.name(), tys: vec![] });
+            collisions.len() - 1
+        });
+        if !collisions[i].tys.contains(&ty) {
+            collisions[i].tys.push(ty);
+        }
+    }
+    collisions.retain(|c| c.tys.len() > 1);
+    collisions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{name_collisions, unambiguous_names};
+    use crate::Ty;
+
+    mod a {
+        pub mod x {
+            pub struct Thing;
+        }
+        pub struct Other;
+    }
+    mod b {
+        pub mod x {
+            pub struct Thing;
+        }
+        pub struct Other;
+    }
+
+    #[test]
+    fn unambiguous() {
+        assert_eq!(unambiguous_names(&[]), Vec::<String>::new());
+        assert_eq!(unambiguous_names(&[Ty::of::<a::x::Thing>()]), ["Thing"]);
+        assert_eq!(
+            unambiguous_names(&[Ty::of::<a::x::Thing>(), Ty::of::<b::x::Thing>(), Ty::of::<a::Other>()]),
+            ["a::x::Thing", "b::x::Thing", "Other"],
+        );
+        assert_eq!(
+            unambiguous_names(&[Ty::of::<Option<a::Other>>(), Ty::of::<Option<b::Other>>(), Ty::of::<Option<a::Other>>()]),
+            ["Option<a::Other>", "Option<b::Other>", "Option<a::Other>"],
+        );
+        assert_eq!(
+            unambiguous_names(&[Ty::of::<std::fmt::Error>(), Ty::of::<std::io::Error>(), Ty::of::<u8>()]),
+            ["fmt::Error", "io::Error", "u8"],
+        );
+        assert_eq!(
+            unambiguous_names(&[Ty::of::<(a::Other, a::x::Thing)>(), Ty::of::<(b::Other, a::x::Thing)>()]),
+            ["(a::Other, Thing)", "(b::Other, Thing)"],
+        );
+        assert_eq!(
+            unambiguous_names(&[Ty::of::<(a::Other, a::x::Thing)>(), Ty::of::<(b::Other, b::x::Thing)>()]),
+            ["(a::Other, Thing)", "(b::Other, Thing)"],
+        );
+        assert_eq!(
+            unambiguous_names(&[Ty::of::<(a::x::Thing, a::Other)>(), Ty::of::<(b::x::Thing, a::Other)>()]),
+            ["(a::x::Thing, Other)", "(b::x::Thing, Other)"],
+        );
+        assert_eq!(
+            unambiguous_names(&[
+                Ty::of::<Result<a::x::Thing, std::fmt::Error>>(),
+                Ty::of::<Result<b::x::Thing, std::io::Error>>(),
+                Ty::of::<Result<b::x::Thing, std::fmt::Error>>(),
+            ]),
+            ["Result<a::x::Thing, fmt::Error>", "Result<Thing, io::Error>", "Result<b::x::Thing, fmt::Error>"],
+        );
+        let f = || ();
+        fn ty_of<T: 'static>(_: &T) -> Ty { Ty::of::<T>() }
+        assert_eq!(unambiguous_names(&[ty_of(&f)]), ["closure in unambiguous"]);
+    }
+
+    #[test]
+    fn collisions() {
+        assert!(name_collisions(&[Ty::of::<a::Other>(), Ty::of::<b::Other>(), Ty::of::<u8>()]).is_empty());
+        let _rules = crate::PrettyRules::builtin().strip_matching("ezty::**").override_scope();
+        let collisions = name_collisions(&[Ty::of::<a::Other>(), Ty::of::<a::x::Thing>(), Ty::of::<b::Other>()]);
+        assert_eq!(collisions.len(), 1);
+        assert_eq!(collisions[0].name, "Other");
+        assert_eq!(collisions[0].tys, [Ty::of::<a::Other>(), Ty::of::<b::Other>()]);
+        assert_eq!(
+            super::unambiguous_names(&[Ty::of::<a::Other>(), Ty::of::<b::Other>()]),
+            ["Other", "Other"],
+        );
+    }
+}